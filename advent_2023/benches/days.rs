@@ -0,0 +1,47 @@
+//! Statistical benchmarks of every day with a cached input, one criterion
+//! group per day and one benchmark per phase. Criterion reports a confidence
+//! interval for each and the change since the last run; `--save-baseline
+//! <name>` and `--baseline <name>` compare against a named run instead.
+//!
+//!     cargo bench -p advent_2023
+//!     cargo bench -p advent_2023 -- day12/
+
+use advent_2023::{day12, DAYS};
+use criterion::{criterion_group, criterion_main, Criterion};
+use utils::cached_input;
+
+const YEAR: i16 = 2023;
+
+fn days(c: &mut Criterion) {
+    for day in DAYS {
+        let Some(input) = cached_input(env!("CARGO_MANIFEST_DIR"), YEAR, day.day) else {
+            eprintln!("no input for day {}, skipping", day.day);
+            continue;
+        };
+        let mut group = c.benchmark_group(format!("day{}", day.day));
+        day.phases(&input, &mut |phase, f| {
+            group.bench_function(phase.as_str(), |b| b.iter(&mut *f));
+        });
+        group.finish();
+    }
+}
+
+/// The part 1 line counters against each other on the same rows.
+fn day12_variants(c: &mut Criterion) {
+    let Some(input) = cached_input(env!("CARGO_MANIFEST_DIR"), YEAR, 12) else {
+        return;
+    };
+    let rows = day12::parse(&input);
+    let mut group = c.benchmark_group("day12_variants");
+    for (name, count) in [
+        ("process_line", day12::process_line as fn(&day12::GearRow) -> u64),
+        ("process_line_dp", day12::process_line_dp),
+        ("process_line_third", day12::process_line_third),
+    ] {
+        group.bench_function(name, |b| b.iter(|| rows.iter().map(count).sum::<u64>()));
+    }
+    group.finish();
+}
+
+criterion_group!(benches, days, day12_variants);
+criterion_main!(benches);
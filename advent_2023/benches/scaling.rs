@@ -0,0 +1,41 @@
+//! How the slow days scale, on generated inputs of growing size. Criterion
+//! plots each group's time against the size in its HTML report, under
+//! `target/criterion/<group>/report`.
+//!
+//!     cargo bench -p advent_2023 --bench scaling
+
+use advent_2023::{day17, day23};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use utils::{digit_grid, junction_maze, Rng};
+
+const SEED: u64 = 2023;
+
+/// Dijkstra over an n x n heat loss map.
+fn day17_part2(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day17_part2");
+    group.sample_size(10);
+    for size in [25, 50, 100, 200] {
+        let input = digit_grid(&mut Rng::new(SEED), size, size);
+        let data = day17::parse(&input);
+        group.bench_with_input(BenchmarkId::from_parameter(size), &data, |b, data| b.iter(|| day17::part2(data)));
+    }
+    group.finish();
+}
+
+/// The longest path search over an n x n lattice of crossings, which is
+/// exponential in the number of crossings.
+fn day23_part2(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day23_part2");
+    group.sample_size(10);
+    for junctions in [2, 3, 4, 5, 6] {
+        let input = junction_maze(&mut Rng::new(SEED), junctions, 5..=20);
+        let data = day23::parse(&input);
+        group.bench_with_input(BenchmarkId::from_parameter(junctions), &data, |b, data| {
+            b.iter(|| day23::part2(data))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, day17_part2, day23_part2);
+criterion_main!(benches);
@@ -1,17 +1,17 @@
+use std::fmt::Display;
 use utils::*;
-use microbench::{self, Options};
 
 fn process_line1(line: &str) -> usize {
     let mut it = line.chars().filter_map(|x| x.to_digit(10));
     let first = it.next().unwrap();
-    let last = it.last().unwrap_or(first);
+    let last = it.next_back().unwrap_or(first);
     (10 * first + last) as usize
 }
 
-fn part1(input: &str) -> usize {
+pub fn part1(input: &str) -> usize {
     input
         .split_terminator("\n")
-        .map(|x| process_line1(x.as_ref()))
+        .map(process_line1)
         .sum()
 }
 
@@ -36,16 +36,34 @@ fn process_line2(mut line: &str) -> usize {
     10 * first.unwrap() + last.unwrap()
 }
 
-fn part2(input: &str) -> usize {
+pub fn part2(input: &str) -> usize {
     input
         .split_terminator("\n")
-        .map(|x| process_line2(x.as_ref()))
+        .map(process_line2)
         .sum()
 }
 
+pub const DAY: Day = Day::new::<Day1>(1);
+
+struct Day1;
+
+impl Solution for Day1 {
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
     #[test]
     fn test_part1() {
         let test_input = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n";
@@ -65,18 +83,3 @@ mod tests {
         assert_eq!(part2(test_input), 281);
     }
 }
-
-fn benchmark(s: &str) {
-    let options = Options::default();
-    microbench::bench(&options, "combined", || {
-        part1(&s);
-        part2(&s);
-    });
-}
-
-fn main() {
-    let s = read_aoc!();
-    println!("{:?}", part1(&s));
-    println!("{:?}", part2(&s));
-    benchmark(&s);
-}
@@ -1,25 +1,26 @@
-use microbench::{self, Options};
+use std::fmt::Display;
 use utils::*;
 
 #[derive(Debug, Default, Clone)]
-struct Entry {
+pub struct Entry {
     neighbors: [[i8; 2]; 2],
 }
 
 impl Entry {
+    #[allow(clippy::wrong_self_convention)]
     fn from_dir(&self, in_dir: [i8; 2]) -> Option<[i8; 2]> {
-        let in_dir_rev = [in_dir[0] * -1, in_dir[1] * -1];
+        let in_dir_rev = [-in_dir[0], -in_dir[1]];
         if self.neighbors[0] == in_dir_rev {
-            Some(self.neighbors[1].clone())
+            Some(self.neighbors[1])
         } else if self.neighbors[1] == in_dir_rev {
-            Some(self.neighbors[0].clone())
+            Some(self.neighbors[0])
         } else {
             None
         }
     }
 }
 
-type Parsed = (Grid<Entry, 2>, Coord<2>);
+pub type Parsed = (Grid<Entry, 2>, Coord<2>);
 
 fn neighbors(c: char) -> [[i8; 2]; 2] {
     match c {
@@ -34,7 +35,7 @@ fn neighbors(c: char) -> [[i8; 2]; 2] {
     }
 }
 
-fn parse(input: &str) -> Parsed {
+pub fn parse(input: &str) -> Parsed {
     let width = input.chars().position(|c| c == '\n').unwrap() as i64;
     let height = input.len() as i64 / (width + 1);
     let mut grid = Grid::new(Entry::default(), &[width, height]);
@@ -54,7 +55,7 @@ fn parse(input: &str) -> Parsed {
     (grid, start.unwrap())
 }
 
-struct LoopInfo {
+pub struct LoopInfo {
     max_distance: u64,
     visited: Grid<bool, 2>,
 }
@@ -66,27 +67,26 @@ fn traverse_loop(grid: &mut Grid<Entry, 2>, start: Coord<2>, mut dir: [i8; 2]) -
     let mut visited = Grid::new(false, &grid.get_dims());
     loop {
         distance += 1;
-        visited.get_mut(cur).map(|x| *x = true);
+        if let Some(x) = visited.get_mut(cur) {
+            *x = true;
+        }
         cur = [
             cur.as_ref()[0] + dir[0] as i64,
             cur.as_ref()[1] + dir[1] as i64,
         ]
         .into();
         if cur == start {
-            grid[start] = Entry{neighbors: [start_dir, [dir[0]*-1, dir[1]*-1]]};
+            grid[start] = Entry{neighbors: [start_dir, [-dir[0], -dir[1]]]};
             return Some(LoopInfo {
                 max_distance: distance / 2,
                 visited,
             });
         }
-        let Some(new_dir) = grid.get(cur).and_then(|x| x.from_dir(dir)) else {
-            return None;
-        };
-        dir = new_dir;
+        dir = grid.get(cur).and_then(|x| x.from_dir(dir))?;
     }
 }
 
-fn part1((grid, start): &mut Parsed) -> LoopInfo {
+pub fn part1((grid, start): &mut Parsed) -> LoopInfo {
     let neighbors = [[1, 0], [-1, 0], [0, 1], [0, -1]];
     neighbors
         .iter()
@@ -95,7 +95,7 @@ fn part1((grid, start): &mut Parsed) -> LoopInfo {
         .unwrap()
 }
 
-fn part2(grid: &Grid<Entry, 2>, visited: Grid<bool, 2>) -> u64 {
+pub fn part2(grid: &Grid<Entry, 2>, visited: Grid<bool, 2>) -> u64 {
     let mut total_area = 0;
 
     for row in 0..visited.get_dims()[1] {
@@ -132,6 +132,26 @@ fn part2(grid: &Grid<Entry, 2>, visited: Grid<bool, 2>) -> u64 {
     total_area
 }
 
+pub const DAY: Day = Day::new::<Day10>(10);
+
+struct Day10;
+
+impl Solution for Day10 {
+    type Parsed<'a> = Parsed;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(&mut data.clone()).max_distance
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        let mut data = data.clone();
+        let loop_info = part1(&mut data);
+        part2(&data.0, loop_info.visited)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     const TEST_INPUT_ONE: &str = "\
@@ -158,38 +178,17 @@ L--J.L7...LJS7F-7L7.
 ....FJL-7.||.||||...
 ....L---J.LJ.LJLJ...
 ";
-    use crate::*;
+    use super::*;
     #[test]
     fn test_input_one() {
-        let mut parsed = parse(&TEST_INPUT_ONE);
+        let mut parsed = parse(TEST_INPUT_ONE);
         let part1_result = part1(&mut parsed);
         assert_eq!(4, part2(&parsed.0, part1_result.visited));
     }
     #[test]
     fn test_input_two() {
-        let mut parsed = parse(&TEST_INPUT_TWO);
+        let mut parsed = parse(TEST_INPUT_TWO);
         let part1_result = part1(&mut parsed);
         assert_eq!(8, part2(&parsed.0, part1_result.visited));
     }
 }
-
-fn benchmark(s: &str) {
-    let options = Options::default();
-    microbench::bench(&options, "parsing", || {
-        parse(&s);
-    });
-    microbench::bench(&options, "combined", || {
-        let mut data = parse(&s);
-        let part1_result = part1(&mut data);
-        part2(&data.0, part1_result.visited);
-    });
-}
-
-fn main() {
-    let s = read_aoc!();
-    let mut data = parse(&s);
-    let part1_result = part1(&mut data);
-    println!("{:?}", part1_result.max_distance);
-    println!("{:?}", part2(&data.0, part1_result.visited));
-    benchmark(&s);
-}
@@ -1,14 +1,14 @@
-use microbench::{self, Options};
+use std::fmt::Display;
 use utils::*;
 
 #[derive(Debug)]
-struct Parsed {
+pub struct Parsed {
     galaxies: Vec<[usize; 2]>,
     empty_rows: Vec<bool>,
     empty_cols: Vec<bool>,
 }
 
-fn parse(input: &str) -> Parsed {
+pub fn parse(input: &str) -> Parsed {
     let width = input.chars().position(|x| x == '\n').unwrap();
     let height = input.len() / (width + 1);
     let mut empty_rows = vec![true; height];
@@ -53,22 +53,39 @@ fn do_it<const MULT: usize>(data: &Parsed) -> usize {
 
     for i in 0..galaxies_adjusted.len() {
         let g1 = galaxies_adjusted[i];
-        for j in 0..i {
-            let g2 = galaxies_adjusted[j];
+        for g2 in &galaxies_adjusted[..i] {
             sum_distances += g1[0].abs_diff(g2[0]) + g1[1].abs_diff(g2[1]);
         }
     }
     sum_distances
 }
 
-fn part1(data: &Parsed) -> usize {
+pub fn part1(data: &Parsed) -> usize {
     do_it::<1>(data)
 }
 
-fn part2(data: &Parsed) -> usize {
+pub fn part2(data: &Parsed) -> usize {
     do_it::<999999>(data)
 }
 
+pub const DAY: Day = Day::new::<Day11>(11);
+
+struct Day11;
+
+impl Solution for Day11 {
+    type Parsed<'a> = Parsed;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     const TEST_INPUT: &str = "\
@@ -83,7 +100,7 @@ mod tests {
 .......#..
 #...#.....
 ";
-    use crate::*;
+    use super::*;
     #[test]
     fn test_part1() {
         assert_eq!(374, part1(&parse(TEST_INPUT)));
@@ -93,22 +110,3 @@ mod tests {
         assert_eq!(1030, do_it::<9>(&parse(TEST_INPUT)));
     }
 }
-
-fn benchmark(s: &str) {
-    let options = Options::default();
-    microbench::bench(&options, "parsing", || {
-        parse(&s);
-    });
-    microbench::bench(&options, "combined", || {
-        let data = parse(&s);
-        (part1(&data), part2(&data))
-    });
-}
-
-fn main() {
-    let s = read_aoc!();
-    let data = parse(&s);
-    println!("{:?}", part1(&data));
-    println!("{:?}", part2(&data));
-    benchmark(&s);
-}
@@ -1,15 +1,15 @@
-use microbench::{self, Options};
+use std::fmt::Display;
 use utils::*;
 
-type Parsed<'a> = Vec<GearRow<'a>>;
+pub type Parsed<'a> = Vec<GearRow<'a>>;
 
 #[derive(Debug)]
-struct GearRow<'a> {
+pub struct GearRow<'a> {
     record: &'a [u8],
     contiguous: Vec<u8>,
 }
 
-fn line_to_row(line: &str) -> GearRow<'_> {
+pub fn line_to_row(line: &str) -> GearRow<'_> {
     let (record, contiguous) = line.split_once(' ').unwrap();
     let contiguous = contiguous.split(',').map(|x| x.parse().unwrap()).to_vec();
     GearRow {
@@ -18,13 +18,18 @@ fn line_to_row(line: &str) -> GearRow<'_> {
     }
 }
 
-fn process_line_third(row: &GearRow<'_>) -> u64 {
-    let mut soln = vec![1; row.record.len()];
+/// Counts arrangements one contiguous group at a time, keeping a running count
+/// per record position.
+pub fn process_line_third(row: &GearRow<'_>) -> u64 {
+    // Ways to place no groups at all up to each position: one, until the first
+    // damaged spring, which nothing would cover.
+    let first_damaged = row.record.iter().position(|&x| x == b'#').unwrap_or(row.record.len());
+    let mut soln: Vec<u64> = (0..row.record.len()).map(|i| (i < first_damaged) as u64).collect();
     let mut default = 1;
     for cont_size in &row.contiguous {
         let cont_size = *cont_size as usize;
         let mut v = vec![false; row.record.len()];
-        for record_index in 0..row.record.len() {
+        for (record_index, can_end) in v.iter_mut().enumerate() {
             let Some(maybe_chunk) = row
                 .record
                 .get((record_index + 1).wrapping_sub(cont_size)..(record_index + 1))
@@ -44,12 +49,17 @@ fn process_line_third(row: &GearRow<'_>) -> u64 {
             {
                 continue;
             }
-            v[record_index] = true;
+            *can_end = true;
         }
         let mut new_soln = vec![1; row.record.len()];
 
         for i in 0..new_soln.len() {
-            new_soln[i] = *new_soln.get(i.wrapping_sub(1)).unwrap_or(&0);
+            // The group can only have ended earlier if this spring isn't
+            // damaged, since no later group would cover it.
+            new_soln[i] = match row.record[i] {
+                b'#' => 0,
+                _ => *new_soln.get(i.wrapping_sub(1)).unwrap_or(&0),
+            };
             if v[i] {
                 new_soln[i] += soln.get(i.wrapping_sub(cont_size+1)).unwrap_or(&default);
             }
@@ -62,7 +72,8 @@ fn process_line_third(row: &GearRow<'_>) -> u64 {
     *soln.last().unwrap()
 }
 
-fn process_line_dp(row: &GearRow<'_>) -> u64 {
+/// Counts arrangements with a table over (record position, group).
+pub fn process_line_dp(row: &GearRow<'_>) -> u64 {
     let mut soln = Grid::new(0, &[row.record.len() as i64, row.contiguous.len() as i64]);
 
     for cont_index in 0..row.contiguous.len() {
@@ -101,18 +112,12 @@ fn process_line_dp(row: &GearRow<'_>) -> u64 {
                 .unwrap_or(&1);
         }
     }
-    for j in 0..soln.get_dims()[1] {
-        for i in 0..soln.get_dims()[0] {
-            print!("{} ", soln[[i, j]])
-        }
-        println!("");
-    }
-    println!("{:?}", soln);
 
     soln[[row.record.len() as i64 - 1, row.contiguous.len() as i64 - 1]]
 }
 
-fn process_line(row: &GearRow<'_>) -> u64 {
+/// Counts arrangements by searching every way to place the groups.
+pub fn process_line(row: &GearRow<'_>) -> u64 {
     let mut num_arrangements = 0;
     let mut search_stack = vec![(0, 0)];
 
@@ -133,7 +138,7 @@ fn process_line(row: &GearRow<'_>) -> u64 {
         else {
             continue;
         };
-        if should_group.iter().any(|x| *x == b'.') {
+        if should_group.contains(&b'.') {
             continue;
         }
         record_index += row.contiguous[cont_index] as usize;
@@ -177,25 +182,109 @@ fn process_line2(row: &GearRow<'_>) -> u64 {
     process_line_third(&row)
 }
 
-fn parse(input: &str) -> Parsed {
+pub fn parse(input: &str) -> Parsed<'_> {
     input
         .split_terminator('\n')
         .map(|line| line_to_row(line))
         .collect()
 }
 
-fn part1(data: &Parsed) -> u64 {
+pub fn part1(data: &Parsed) -> u64 {
     data.iter().map(|row| process_line(row)).sum()
 }
 
-fn part2(data: &Parsed) -> u64 {
+pub fn part2(data: &Parsed) -> u64 {
     data.iter().map(|row| process_line2(row)).sum()
 }
 
+pub const DAY: Day = Day::new::<Day12>(12);
+
+struct Day12;
+
+impl Solution for Day12 {
+    type Parsed<'a> = Parsed<'a>;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     // const TEST_INPUT: &str = "";
-    use crate::*;
+    use super::*;
+
+    /// A random line like `?#.?? 1,2`, kept small enough for `process_line`'s
+    /// search to run on the unfolded row too.
+    fn random_line(rng: &mut Rng) -> (String, Vec<u8>) {
+        let record = (0..rng.range(1..=8)).map(|_| *rng.choose(&['.', '#', '?'])).collect();
+        let contiguous = (0..rng.range(1..=3)).map(|_| rng.range(1..=3) as u8).collect();
+        (record, contiguous)
+    }
+
+    /// Shorter records with fewer unknowns, and fewer, smaller groups.
+    fn shrink_line((record, contiguous): &(String, Vec<u8>)) -> Vec<(String, Vec<u8>)> {
+        let chars: Vec<_> = record.chars().collect();
+        let records = shrink_vec(&chars, |&c| if c == '.' { vec![] } else { vec!['.'] });
+        let groups = shrink_vec(contiguous, |&n| if n > 1 { vec![n - 1] } else { vec![] });
+        let shrunk_records = records.into_iter().map(|r| (r.into_iter().collect(), contiguous.clone()));
+        let shrunk_groups = groups.into_iter().map(|g| (record.clone(), g));
+        shrunk_records
+            .chain(shrunk_groups)
+            .filter(|(r, g)| !r.is_empty() && !g.is_empty())
+            .collect()
+    }
+
+    fn with_row(f: fn(&GearRow) -> u64) -> impl Fn(&(String, Vec<u8>)) -> u64 {
+        move |(record, contiguous)| {
+            f(&GearRow {
+                record: record.as_bytes(),
+                contiguous: contiguous.clone(),
+            })
+        }
+    }
+
+    /// The line repeated five times, as part 2 reads it.
+    fn unfold((record, contiguous): &(String, Vec<u8>)) -> (String, Vec<u8>) {
+        ([record.as_str(); 5].join("?"), contiguous.repeat(5))
+    }
+
+    #[test]
+    fn test_variants_agree() {
+        Differential::new(random_line)
+            .variant("process_line", with_row(process_line))
+            .variant("process_line_third", with_row(process_line_third))
+            .with_shrink(shrink_line)
+            .assert_agree();
+    }
+
+    #[test]
+    fn test_unfolded_agree() {
+        Differential::new(random_line)
+            .variant("process_line unfolded", |line| with_row(process_line)(&unfold(line)))
+            .variant("process_line2", with_row(process_line2))
+            .with_shrink(shrink_line)
+            .assert_agree();
+    }
+
+    /// `process_line_dp` is unfinished and still counts a group that doesn't
+    /// fit. This fails once it's fixed, so it can join `test_variants_agree`.
+    #[test]
+    fn test_dp_known_disagreement() {
+        let disagreement = Differential::new(|_| ("#".to_string(), vec![1, 1]))
+            .variant("process_line", with_row(process_line))
+            .variant("process_line_dp", with_row(process_line_dp))
+            .with_cases(1)
+            .run()
+            .unwrap_err();
+        assert_eq!(vec![("process_line", 0), ("process_line_dp", 1)], disagreement.outputs);
+    }
     #[test]
     fn test_part1() {
         let line = "???.### 1,1,3";
@@ -223,30 +312,3 @@ mod tests {
         assert_eq!(506250, process_line2(&line_to_row(line)));
     }
 }
-
-fn benchmark(s: &str) {
-    let options = Options::default();
-    microbench::bench(&options, "parsing", || {
-        parse(&s);
-    });
-    let data = parse(&s);
-    microbench::bench(&options, "part1", || {
-        part1(&data);
-    });
-    microbench::bench(&options, "part2", || {
-        part2(&data);
-    });
-    microbench::bench(&options, "combined", || {
-        let data = parse(&s);
-        part1(&data);
-        part2(&data);
-    });
-}
-
-fn main() {
-    let s = read_aoc!();
-    let data = parse(&s);
-    println!("{:?}", part1(&data));
-    println!("{:?}", part2(&data));
-    benchmark(&s);
-}
@@ -0,0 +1,188 @@
+use std::fmt::Display;
+use utils::*;
+
+/// How many cells differ between two rows of `view`, stopping early once it's
+/// more than `TARGET`. `None` if either row is off the view.
+fn row_difference<const TARGET: u8>(view: GridView<u8>, row1: usize, row2: usize) -> Option<u8> {
+    let [width, height] = view.get_dims();
+    if row1 >= height as usize || row2 >= height as usize {
+        return None;
+    }
+    let mut dissimilarity = 0;
+    for col in 0..width {
+        if view[[col, row1 as i64]] != view[[col, row2 as i64]] {
+            dissimilarity += 1;
+            if dissimilarity > TARGET {
+                return Some(TARGET + 1);
+            }
+        }
+    }
+    Some(dissimilarity)
+}
+
+fn next_pattern(input: &str) -> (&str, Grid<u8, 2>) {
+    let length = 1 + input.find("\n\n").unwrap_or(input.len() - 1);
+    let pattern = Grid::parse(&input[..length], |b, _| Ok::<_, UnknownCell>(b)).unwrap();
+    (input.get((length + 1)..).unwrap_or(""), pattern)
+}
+
+fn reflection<const TARGET: u8>(
+    range: usize,
+    f: impl Fn(usize, usize) -> Option<u8>,
+) -> Option<usize> {
+    'outer: for start in 0..(range - 1) {
+        let mut dissimilarity = 0;
+        for dist in 0.. {
+            let i = start.wrapping_sub(dist);
+            let j = start + 1 + dist;
+            match f(i, j) {
+                None => break,
+                Some(x) => dissimilarity += x,
+            }
+            if dissimilarity > TARGET {
+                continue 'outer;
+            }
+        }
+        if dissimilarity == TARGET {
+            return Some(start + 1);
+        }
+    }
+    None
+}
+
+/// Rows above a mirror between rows of `view`.
+fn row_reflection<const TARGET: u8>(view: GridView<u8>) -> Option<usize> {
+    reflection::<TARGET>(view.get_dims()[1] as usize, |x, y| row_difference::<TARGET>(view, x, y))
+}
+fn vertical_reflection<const TARGET: u8>(pattern: &Grid<u8, 2>) -> Option<usize> {
+    row_reflection::<TARGET>(pattern.view().transpose())
+}
+fn horizontal_reflection<const TARGET: u8>(pattern: &Grid<u8, 2>) -> Option<usize> {
+    row_reflection::<TARGET>(pattern.view())
+}
+
+pub type Parsed = Vec<Grid<u8, 2>>;
+
+pub fn parse(input: &str) -> Parsed {
+    let mut rest = input;
+    let mut patterns = vec![];
+    while !rest.is_empty() {
+        let (r, pattern) = next_pattern(rest);
+        rest = r;
+        patterns.push(pattern);
+    }
+    patterns
+}
+
+fn solve<const TARGET: u8>(patterns: &Parsed) -> usize {
+    let mut sum = 0;
+    for pattern in patterns {
+        if let Some(h) = horizontal_reflection::<TARGET>(pattern) {
+            sum += 100 * h;
+            continue;
+        }
+        sum += vertical_reflection::<TARGET>(pattern).unwrap();
+    }
+    sum
+}
+
+pub fn part1(patterns: &Parsed) -> usize {
+    solve::<0>(patterns)
+}
+
+pub fn part2(patterns: &Parsed) -> usize {
+    solve::<1>(patterns)
+}
+
+pub const DAY: Day = Day::new::<Day13>(13);
+
+struct Day13;
+
+impl Solution for Day13 {
+    type Parsed<'a> = Parsed;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    const TEST_INPUT: &str = "\
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#
+";
+
+    const TEST_INPUT2: &str = "\
+...#.###...
+###.##.##.#
+#...#.##.#.
+.####..####
+##..###.#..
+.#.#..#.#.#
+#....####.#
+#....####.#
+.#.#..#.#.#
+##..###.#..
+.####..#.##
+#...#.##.#.
+###.##.##.#
+...#.###...
+...#.###...
+";
+
+    use super::*;
+    #[test]
+    fn test_part1() {
+        let (rest, first) = next_pattern(TEST_INPUT);
+        let (rest, second) = next_pattern(rest);
+        assert_eq!("", rest);
+
+        assert_eq!(Some(5), vertical_reflection::<0>(&first));
+        assert_eq!(None, vertical_reflection::<0>(&second));
+
+        assert_eq!(None, horizontal_reflection::<0>(&first));
+        assert_eq!(Some(4), horizontal_reflection::<0>(&second));
+        assert_eq!(405, part1(&parse(TEST_INPUT)));
+    }
+
+    #[test]
+    fn test_start_input() {
+        let (rest, first) = next_pattern(TEST_INPUT2);
+        assert_eq!("", rest);
+        assert_eq!(Some(14), horizontal_reflection::<0>(&first));
+    }
+
+    #[test]
+    fn test_part2() {
+        let (rest, first) = next_pattern(TEST_INPUT);
+        let (_, second) = next_pattern(rest);
+
+
+        assert_eq!(Some(3), horizontal_reflection::<1>(&first));
+        assert_eq!(None, vertical_reflection::<1>(&first));
+
+        assert_eq!(Some(1), horizontal_reflection::<1>(&second));
+        assert_eq!(None, vertical_reflection::<1>(&second));
+        assert_eq!(400, part2(&parse(TEST_INPUT)));
+    }
+}
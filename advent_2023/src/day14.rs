@@ -1,37 +1,33 @@
-use microbench::{self, Options};
+use std::fmt::Display;
 use utils::*;
 
-type Parsed = Grid<Entry, 2>;
+pub type Parsed = Grid<Entry, 2>;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-enum Entry {
+#[allow(non_camel_case_types)]
+pub enum Entry {
     SPACE,
     ROUND_ROCK,
     CUBE_ROCK,
 }
 
-fn print(grid: &Grid<Entry, 2>) {
-    for row in 0..grid.get_dims()[1] {
-        let s: String = (0..grid.get_dims()[0])
-            .map(|col| match grid[[col, row]] {
-                Entry::SPACE => '.',
-                Entry::ROUND_ROCK => 'O',
-                Entry::CUBE_ROCK => '#',
-            })
-            .collect();
-        println!("{s}");
+impl CellChar for Entry {
+    fn cell_char(&self) -> char {
+        match self {
+            Entry::SPACE => '.',
+            Entry::ROUND_ROCK => 'O',
+            Entry::CUBE_ROCK => '#',
+        }
     }
 }
 
-fn parse(input: &str) -> Parsed {
+pub fn parse(input: &str) -> Parsed {
     let width = input.find('\n').unwrap() as i64;
     let height = input.len() as i64 / (width + 1);
     let mut grid = Grid::new(Entry::SPACE, &[width, height]);
     let mut row = 0;
     let mut col = 0;
 
-    let mut num_round = 0;
-
     for c in input.as_bytes() {
         match *c {
             b'\n' => {
@@ -41,7 +37,6 @@ fn parse(input: &str) -> Parsed {
             }
             b'O' => {
                 grid[[col, row]] = Entry::ROUND_ROCK;
-                num_round += 1;
             }
             b'#' => {
                 grid[[col, row]] = Entry::CUBE_ROCK;
@@ -54,7 +49,7 @@ fn parse(input: &str) -> Parsed {
     grid
 }
 
-fn part1(input: &str) -> usize {
+pub fn part1(input: &str) -> usize {
     let width = input.find('\n').unwrap();
     let height = input.len() / (width + 1);
 
@@ -85,39 +80,20 @@ fn part1(input: &str) -> usize {
     load
 }
 
-// SHIFT_DIR: 1 means shifting towards 0, -1 means shifting towards higher coordinates
-// AXIS: 0 
-// WIP
-// fn tilt<const SHIFT_DIR: i64, const AXIS: usize>(
-//     grid: &mut Grid<Entry, 2>,
-//     points: impl Iterator<Item = Coord<2>>,
-//     dests: &mut Vec<i64>,
-// ) {
-//     for p in points {
-//         match grid[p] {
-//             Entry::SPACE => (),
-//             Entry::CUBE_ROCK => dests[p.as_ref()[AXIS] as usize] = p.as_ref()[1-AXIS] + SHIFT_DIR,
-//             Entry::ROUND_ROCK => {
-//                 grid[p] = Entry::SPACE;
-//                 let p2 = p;
-//                 dests[p.as_ref()[AXIS] as usize] += SHIFT_DIR;
-//             }
-//         }
-//     }
-// }
-
-fn tilt_north(grid: &mut Grid<Entry, 2>) {
-    let mut dests = vec![0; grid.get_dims()[0] as usize];
+/// Rolls every round rock as far towards the top of `view` as it goes.
+fn tilt(mut view: GridViewMut<Entry>) {
+    let [width, height] = view.get_dims();
+    let mut dests = vec![0; width as usize];
 
-    for row in 0..grid.get_dims()[1] {
-        for col in 0..grid.get_dims()[0] {
+    for row in 0..height {
+        for col in 0..width {
             let col_u = col as usize;
-            match grid[[col, row]] {
+            match view[[col, row]] {
                 Entry::SPACE => (),
                 Entry::CUBE_ROCK => dests[col_u] = row + 1,
                 Entry::ROUND_ROCK => {
-                    grid[[col, row]] = Entry::SPACE;
-                    grid[[col, dests[col_u]]] = Entry::ROUND_ROCK;
+                    view[[col, row]] = Entry::SPACE;
+                    view[[col, dests[col_u]]] = Entry::ROUND_ROCK;
                     dests[col_u] += 1;
                 }
             }
@@ -125,64 +101,23 @@ fn tilt_north(grid: &mut Grid<Entry, 2>) {
     }
 }
 
-fn tilt_south(grid: &mut Grid<Entry, 2>) {
-    let mut dests = vec![grid.get_dims()[1] - 1; grid.get_dims()[0] as usize];
+fn tilt_north(grid: &mut Grid<Entry, 2>) {
+    tilt(grid.view_mut());
+}
 
-    for row in (0..grid.get_dims()[1]).rev() {
-        for col in 0..grid.get_dims()[0] {
-            let col_u = col as usize;
-            match grid[[col, row]] {
-                Entry::SPACE => (),
-                Entry::CUBE_ROCK => dests[col_u] = row - 1,
-                Entry::ROUND_ROCK => {
-                    grid[[col, row]] = Entry::SPACE;
-                    grid[[col, dests[col_u]]] = Entry::ROUND_ROCK;
-                    dests[col_u] -= 1;
-                }
-            }
-        }
-    }
+fn tilt_south(grid: &mut Grid<Entry, 2>) {
+    tilt(grid.view_mut().flip_vertical());
 }
 
 fn tilt_west(grid: &mut Grid<Entry, 2>) {
-    let mut dests = vec![0; grid.get_dims()[1] as usize];
-
-    for col in 0..grid.get_dims()[0] {
-        for row in 0..grid.get_dims()[1] {
-            let row_u = row as usize;
-            match grid[[col, row]] {
-                Entry::SPACE => (),
-                Entry::CUBE_ROCK => dests[row_u] = col + 1,
-                Entry::ROUND_ROCK => {
-                    grid[[col, row]] = Entry::SPACE;
-                    grid[[dests[row_u], row]] = Entry::ROUND_ROCK;
-                    dests[row_u] += 1;
-                }
-            }
-        }
-    }
+    tilt(grid.view_mut().transpose());
 }
 
 fn tilt_east(grid: &mut Grid<Entry, 2>) {
-    let mut dests = vec![grid.get_dims()[0] - 1; grid.get_dims()[1] as usize];
-
-    for col in (0..grid.get_dims()[0]).rev() {
-        for row in 0..grid.get_dims()[1] {
-            let row_u = row as usize;
-            match grid[[col, row]] {
-                Entry::SPACE => (),
-                Entry::CUBE_ROCK => dests[row_u] = col - 1,
-                Entry::ROUND_ROCK => {
-                    grid[[col, row]] = Entry::SPACE;
-                    grid[[dests[row_u], row]] = Entry::ROUND_ROCK;
-                    dests[row_u] -= 1;
-                }
-            }
-        }
-    }
+    tilt(grid.view_mut().transpose().flip_vertical());
 }
 
-fn part2(grid: &Parsed) -> i64 {
+pub fn part2(grid: &Parsed) -> i64 {
     let mut grid = grid.clone();
     let mut seen = HashMap::new();
     let mut cycle_counter = 0;
@@ -229,6 +164,24 @@ fn part2(grid: &Parsed) -> i64 {
     total_load
 }
 
+pub const DAY: Day = Day::new::<Day14>(14);
+
+struct Day14;
+
+impl Solution for Day14 {
+    type Parsed<'a> = (&'a str, Parsed);
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        (input, parse(input))
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data.0)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(&data.1)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     const TEST_INPUT: &str = "\
@@ -283,7 +236,7 @@ O.#..O.#.#
 #.OOO#...O
 ";
 
-    use crate::*;
+    use super::*;
     #[test]
     fn test_part1() {
         let mut grid = parse(TEST_INPUT);
@@ -311,34 +264,7 @@ O.#..O.#.#
     }
     #[test]
     fn test_part2_2() {
-        let data = parse(&TEST_INPUT);
+        let data = parse(TEST_INPUT);
         assert_eq!(64, part2(&data));
     }
 }
-
-fn benchmark(s: &str) {
-    let options = Options::default();
-    microbench::bench(&options, "parsing", || {
-        parse(&s);
-    });
-    let data = parse(&s);
-    microbench::bench(&options, "part1", || {
-        part1(&s);
-    });
-    microbench::bench(&options, "part2", || {
-        part2(&data);
-    });
-    microbench::bench(&options, "combined", || {
-        let data = parse(&s);
-        part1(&s);
-        part2(&data);
-    });
-}
-
-fn main() {
-    let s = read_aoc!();
-    let data = parse(&s);
-    println!("{:?}", part1(&s));
-    println!("{:?}", part2(&data));
-    benchmark(&s);
-}
@@ -1,4 +1,4 @@
-use microbench::{self, Options};
+use std::fmt::Display;
 use utils::*;
 
 fn hash(s: &[u8]) -> u32 {
@@ -9,16 +9,15 @@ fn hash(s: &[u8]) -> u32 {
     })
 }
 
-fn part1(input: &str) -> u32 {
+pub fn part1(input: &str) -> u32 {
     input[..input.len()-1].split(',').map(|s| hash(s.as_bytes())).sum()
 }
 
-fn part2(input: &str) -> usize {
+pub fn part2(input: &str) -> usize {
     let mut boxes: [Vec<(&str, u32)>; 256] = std::array::from_fn(|_| Vec::with_capacity(10));
 
     for s in input[..input.len() - 1].split(',') {
-        if s.ends_with("-") {
-            let label = &s[..s.len() - 1];
+        if let Some(label) = s.strip_suffix("-") {
             let cur_box = &mut boxes[hash(label.as_bytes()) as usize];
             let found = cur_box.iter().position(|x| x.0 == label);
             found.map(|x| cur_box.remove(x));
@@ -43,10 +42,28 @@ fn part2(input: &str) -> usize {
     focusing_power
 }
 
+pub const DAY: Day = Day::new::<Day15>(15);
+
+struct Day15;
+
+impl Solution for Day15 {
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     const TEST_INPUT: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7\n";
-    use crate::*;
+    use super::*;
     #[test]
     fn test_hash() {
         let results = [30, 253, 97, 47, 14, 180, 9, 197, 48, 214, 231];
@@ -59,24 +76,3 @@ mod tests {
         assert_eq!(145, part2(TEST_INPUT));
     }
 }
-
-fn benchmark(s: &str) {
-    let options = Options::default();
-    microbench::bench(&options, "part1", || {
-        part1(&s);
-    });
-    microbench::bench(&options, "part2", || {
-        part2(&s);
-    });
-    microbench::bench(&options, "combined", || {
-        part1(&s);
-        part2(&s);
-    });
-}
-
-fn main() {
-    let s = read_aoc!();
-    println!("{:?}", part1(&s));
-    println!("{:?}", part2(&s));
-    benchmark(&s);
-}
@@ -1,8 +1,8 @@
-use microbench::{self, Options};
+use std::fmt::Display;
 use utils::*;
 
 #[derive(Debug, Clone, Copy)]
-enum Entry {
+pub enum Entry {
     Empty,
     SplitterVert,
     SplitterHor,
@@ -10,9 +10,9 @@ enum Entry {
     MirrorBackslash,
 }
 
-type Parsed = Grid<Entry, 2>;
+pub type Parsed = Grid<Entry, 2>;
 
-fn parse(input: &str) -> Parsed {
+pub fn parse(input: &str) -> Parsed {
     let input = input.as_bytes();
     let width = input.iter().position(|&x| x == b'\n').unwrap() as i64;
     let height = input.len() as i64 / (width + 1);
@@ -45,10 +45,7 @@ fn num_energized(input: &Parsed, starting_coord: Coord<2>, starting_dir: Coord<2
     let mut beam_stack = vec![(starting_coord, starting_dir)];
 
     while let Some((mut beam_loc, mut beam_dir)) = beam_stack.pop() {
-        loop {
-            let Some(r) = energized.get_mut(beam_loc) else {
-                break;
-            };
+        while let Some(r) = energized.get_mut(beam_loc) {
             let dir_num = match beam_dir.as_ref() {
                 [1, _] => 0,
                 [-1, _] => 1,
@@ -96,11 +93,11 @@ fn num_energized(input: &Parsed, starting_coord: Coord<2>, starting_dir: Coord<2
         .count()
 }
 
-fn part1(input: &Parsed) -> usize {
+pub fn part1(input: &Parsed) -> usize {
     num_energized(input, [0, 0].into(), [1, 0].into())
 }
 
-fn part2(data: &Parsed) -> usize {
+pub fn part2(data: &Parsed) -> usize {
     let [width, height] = data.get_dims();
     let left = (0..height).map(|row| ([0, row], [1, 0]));
     let right = (0..height).map(|row| ([width-1, row], [-1, 0]));
@@ -115,6 +112,24 @@ fn part2(data: &Parsed) -> usize {
         .unwrap()
 }
 
+pub const DAY: Day = Day::new::<Day16>(16);
+
+struct Day16;
+
+impl Solution for Day16 {
+    type Parsed<'a> = Parsed;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     const TEST_INPUT: &str = r".|...\....
@@ -128,36 +143,9 @@ mod tests {
 .|....-|.\
 ..//.|....
 ";
-    use crate::*;
+    use super::*;
     #[test]
     fn test_part1() {
         assert_eq!(46, part1(&parse(TEST_INPUT)));
     }
 }
-
-fn benchmark(s: &str) {
-    let options = Options::default();
-    microbench::bench(&options, "parsing", || {
-        parse(&s);
-    });
-    let data = parse(&s);
-    microbench::bench(&options, "part1", || {
-        part1(&data);
-    });
-    microbench::bench(&options, "part2", || {
-        part2(&data);
-    });
-    microbench::bench(&options, "combined", || {
-        let data = parse(&s);
-        part1(&data);
-        part2(&data);
-    });
-}
-
-fn main() {
-    let s = read_aoc!();
-    let data = parse(&s);
-    println!("{:?}", part1(&data));
-    println!("{:?}", part2(&data));
-    benchmark(&s);
-}
@@ -1,32 +1,19 @@
 use std::{cmp::Reverse, collections::BinaryHeap};
+use std::fmt::Display;
 
-use microbench::{self, Options};
 use utils::*;
 
-type Parsed = Grid<u8, 2>;
+pub type Parsed = Grid<u8, 2>;
 
-fn parse(input: &str) -> Parsed {
-    let input = input.as_bytes();
-    let width = input.iter().position(|&x| x == b'\n').unwrap() as i64;
-    let height = input.len() as i64 / (width + 1);
-
-    let mut grid = Grid::new(0, &[width, height]);
-    let mut col = 0;
-    let mut row = 0;
-
-    for &b in input.iter() {
-        if b == b'\n' {
-            row += 1;
-            col = 0;
-            continue;
-        }
-        grid[[col, row]] = b - b'0';
-        col += 1;
-    }
-    grid
+pub fn parse(input: &str) -> Parsed {
+    Grid::parse(input, |b, _| match b {
+        b'0'..=b'9' => Ok(b - b'0'),
+        b => Err(UnknownCell(b)),
+    })
+    .unwrap()
 }
 
-fn part1(data: &Parsed) -> u32 {
+pub fn part1(data: &Parsed) -> u32 {
     let mut heap = BinaryHeap::from([
         Reverse((data[[0, 1]] as u32, [0, 1], [0, 1], 0)),
         Reverse((data[[1, 0]] as u32, [1, 0], [1, 0], 0)),
@@ -73,7 +60,7 @@ fn part1(data: &Parsed) -> u32 {
     }
 }
 
-fn part2(data: &Parsed) -> u32 {
+pub fn part2(data: &Parsed) -> u32 {
     let mut heap = BinaryHeap::from([
         Reverse((data[[0, 1]] as u32, [0, 1], [0, 1], 0)),
         Reverse((data[[1, 0]] as u32, [1, 0], [1, 0], 0)),
@@ -123,6 +110,24 @@ fn part2(data: &Parsed) -> u32 {
     }
 }
 
+pub const DAY: Day = Day::new::<Day17>(17);
+
+struct Day17;
+
+impl Solution for Day17 {
+    type Parsed<'a> = Parsed;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     const TEST_INPUT: &str = "\
@@ -140,7 +145,7 @@ mod tests {
 2546548887735
 4322674655533
 ";
-    use crate::*;
+    use super::*;
     #[test]
     fn test_part1() {
         assert_eq!(102, part1(&parse(TEST_INPUT)));
@@ -150,30 +155,3 @@ mod tests {
         assert_eq!(94, part2(&parse(TEST_INPUT)));
     }
 }
-
-fn benchmark(s: &str) {
-    let options = Options::default();
-    microbench::bench(&options, "parsing", || {
-        parse(&s);
-    });
-    let data = parse(&s);
-    microbench::bench(&options, "part1", || {
-        part1(&data);
-    });
-    microbench::bench(&options, "part2", || {
-        part2(&data);
-    });
-    microbench::bench(&options, "combined", || {
-        let data = parse(&s);
-        part1(&data);
-        part2(&data);
-    });
-}
-
-fn main() {
-    let s = read_aoc!();
-    let data = parse(&s);
-    println!("{:?}", part1(&data));
-    println!("{:?}", part2(&data));
-    benchmark(&s);
-}
@@ -1,9 +1,9 @@
-use microbench::{self, Options};
+use std::fmt::Display;
 use utils::*;
 
-type Parsed<'a> = Vec<(char, i32, &'a str)>;
+pub type Parsed<'a> = Vec<(char, i32, &'a str)>;
 
-fn parse(input: &str) -> Parsed {
+pub fn parse(input: &str) -> Parsed<'_> {
     input
         .split_terminator('\n')
         .map(|line| {
@@ -43,11 +43,11 @@ fn solve<I: Iterator<Item = (char, i32)>>(it: I) -> i64 {
     i + length
 }
 
-fn part1(data: &Parsed) -> i64 {
+pub fn part1(data: &Parsed) -> i64 {
     solve(data.iter().map(|&e| (e.0, e.1)))
 }
 
-fn part2(data: &Parsed) -> i64 {
+pub fn part2(data: &Parsed) -> i64 {
     solve(data.iter().map(|e| {
         let len = i64::from_str_radix(&e.2[..e.2.len() - 1], 16).unwrap() as i32;
         let dir = match e.2.as_bytes().last().unwrap() {
@@ -62,6 +62,24 @@ fn part2(data: &Parsed) -> i64 {
     ))
 }
 
+pub const DAY: Day = Day::new::<Day18>(18);
+
+struct Day18;
+
+impl Solution for Day18 {
+    type Parsed<'a> = Parsed<'a>;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     const TEST_INPUT: &str = "\
@@ -80,7 +98,7 @@ U 3 (#a77fa3)
 L 2 (#015232)
 U 2 (#7a21e3)   
 ";
-    use crate::*;
+    use super::*;
     #[test]
     fn test_part1() {
         assert_eq!(62, part1(&parse(TEST_INPUT)));
@@ -90,30 +108,3 @@ U 2 (#7a21e3)
         assert_eq!(0, part1(&parse(TEST_INPUT)));
     }
 }
-
-fn benchmark(s: &str) {
-    let options = Options::default();
-    microbench::bench(&options, "parsing", || {
-        parse(&s);
-    });
-    let data = parse(&s);
-    microbench::bench(&options, "part1", || {
-        part1(&data);
-    });
-    microbench::bench(&options, "part2", || {
-        part2(&data);
-    });
-    microbench::bench(&options, "combined", || {
-        let data = parse(&s);
-        part1(&data);
-        part2(&data);
-    });
-}
-
-fn main() {
-    let s = read_aoc!();
-    let data = parse(&s);
-    println!("{:?}", part1(&data));
-    println!("{:?}", part2(&data));
-    benchmark(&s);
-}
@@ -1,4 +1,4 @@
-use microbench::{self, Options};
+use std::fmt::Display;
 use utils::*;
 
 type PartRating = i16;
@@ -7,7 +7,7 @@ const ACCEPT: DestIndex = DestIndex::MAX;
 const REJECT: DestIndex = DestIndex::MAX - 1;
 
 #[derive(Debug)]
-struct Part {
+pub struct Part {
     ratings: [PartRating; 4],
 }
 
@@ -31,12 +31,12 @@ impl Rule {
 }
 
 #[derive(Default, Debug)]
-struct Workflow {
+pub struct Workflow {
     rules: Vec<Rule>,
     fallback: DestIndex,
 }
 
-type Parsed = (Vec<Workflow>, Vec<Part>);
+pub type Parsed = (Vec<Workflow>, Vec<Part>);
 
 fn get_index<'a, 'b: 'a>(
     index_map: &mut HashMap<&'a str, DestIndex>,
@@ -49,7 +49,7 @@ fn get_index<'a, 'b: 'a>(
     })
 }
 
-fn parse(input: &str) -> Parsed {
+pub fn parse(input: &str) -> Parsed {
     let (workflow_text, part_text) = input.split_once("\n\n").unwrap();
 
     let mut workflows = vec![Workflow::default()];
@@ -120,7 +120,7 @@ fn parse(input: &str) -> Parsed {
     (workflows, parts)
 }
 
-fn part1(data: &Parsed) -> u64 {
+pub fn part1(data: &Parsed) -> u64 {
     let (workflows, parts) = data;
     parts
         .iter()
@@ -170,7 +170,7 @@ fn apply_rule_to_range(
         part.ranges[rule.field as usize][0] = rule.threshold;
     }
 
-    return (Some((rule.dest, sent_part)), Some(part))
+    (Some((rule.dest, sent_part)), Some(part))
 }
 
 fn handle_destination(
@@ -186,7 +186,7 @@ fn handle_destination(
     0
 }
 
-fn part2(data: &Parsed) -> u64 {
+pub fn part2(data: &Parsed) -> u64 {
     let (workflows, _) = data;
     let ranges = std::array::from_fn(|_| [1, 4000]);
     let mut processing_ranges = vec![(0, PartRange { ranges })];
@@ -200,39 +200,32 @@ fn part2(data: &Parsed) -> u64 {
                 break;
             };
             let (sent, stay) = apply_rule_to_range(rule, range);
-            sent.map(|x| accepted_ranges += handle_destination(&mut processing_ranges, x));
+            if let Some(x) = sent {
+                accepted_ranges += handle_destination(&mut processing_ranges, x);
+            }
             maybe_range = stay;
         }
-        maybe_range.map(|x| {
-            accepted_ranges += handle_destination(&mut processing_ranges, (workflow.fallback, x))
-        });
+        if let Some(x) = maybe_range {
+            accepted_ranges += handle_destination(&mut processing_ranges, (workflow.fallback, x));
+        }
     }
     accepted_ranges
 }
 
-fn benchmark(s: &str) {
-    let options = Options::default();
-    microbench::bench(&options, "parsing", || {
-        parse(&s);
-    });
-    let data = parse(&s);
-    microbench::bench(&options, "part1", || {
-        part1(&data);
-    });
-    microbench::bench(&options, "part2", || {
-        part2(&data);
-    });
-    microbench::bench(&options, "combined", || {
-        let data = parse(&s);
-        part1(&data);
-        part2(&data);
-    });
-}
+pub const DAY: Day = Day::new::<Day19>(19);
+
+struct Day19;
 
-fn main() {
-    let s = read_aoc!();
-    let data = parse(&s);
-    println!("{:?}", part1(&data));
-    println!("{:?}", part2(&data));
-    benchmark(&s);
+impl Solution for Day19 {
+    type Parsed<'a> = Parsed;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
 }
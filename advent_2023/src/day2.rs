@@ -1,4 +1,5 @@
 use std::cmp::max;
+use std::fmt::Display;
 
 use utils::*;
 
@@ -48,7 +49,7 @@ const BAG: Colors = Colors {
 
 fn parse_line(line: &str) -> (&str, impl Iterator<Item=Colors> + '_) {
     let (id, game) = line.split_once(": ").unwrap();
-    (id, game.split("; ").map(|s| Colors::new(s)))
+    (id, game.split("; ").map(Colors::new))
 }
 
 fn process_line1(line: &str) -> i64{
@@ -61,8 +62,8 @@ fn process_line1(line: &str) -> i64{
     }
 }
 
-fn part1(input: &str) -> i64 {
-    input.split_terminator('\n').map(|l| process_line1(l)).sum()
+pub fn part1(input: &str) -> i64 {
+    input.split_terminator('\n').map(process_line1).sum()
 }
 
 fn process_line2(line: &str) -> i64{
@@ -70,12 +71,24 @@ fn process_line2(line: &str) -> i64{
     turns.fold(Colors::default(), |acc: Colors, e| acc.max_of(&e)).power_of()
 }
 
-fn part2(input: &str) -> i64 {
-    input.split_terminator('\n').map(|l| process_line2(l)).sum()
+pub fn part2(input: &str) -> i64 {
+    input.split_terminator('\n').map(process_line2).sum()
 }
 
-fn main() {
-    let s = read_aoc!();
-    println!("{:?}", part1(&s));
-    println!("{:?}", part2(&s));
+pub const DAY: Day = Day::new::<Day2>(2);
+
+struct Day2;
+
+impl Solution for Day2 {
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
 }
@@ -1,5 +1,5 @@
-use microbench::{self, Options};
 use std::collections::VecDeque;
+use std::fmt::Display;
 use utils::*;
 
 type IndexType = u16;
@@ -35,13 +35,13 @@ enum ModuleKind {
 }
 
 #[derive(Debug, Clone)]
-struct Module {
+pub struct Module {
     outputs: Vec<IndexType>,
     last_pulse: PulseHeight,
     kind: ModuleKind,
 }
 
-type Parsed = Vec<Module>;
+pub type Parsed = Vec<Module>;
 
 impl Default for Module {
     fn default() -> Self {
@@ -64,7 +64,7 @@ fn get_index<'a, 'b: 'a>(
     })
 }
 
-fn parse(input: &str) -> Parsed {
+pub fn parse(input: &str) -> Parsed {
     let mut modules = vec![
         Module {
             kind: ModuleKind::Broadcaster,
@@ -113,7 +113,7 @@ fn parse(input: &str) -> Parsed {
 }
 
 fn press_button<F: FnMut(&Pulse) -> bool>(
-    modules: &mut Vec<Module>,
+    modules: &mut [Module],
     pulses: &mut VecDeque<Pulse>,
     mut f: F,
 ) -> bool {
@@ -156,7 +156,7 @@ fn press_button<F: FnMut(&Pulse) -> bool>(
             ModuleKind::Broadcaster => unreachable!(),
             ModuleKind::Test => None,
         };
-        maybe_height.map(|new_height| {
+        if let Some(new_height) = maybe_height {
             for &o in &cur_module.outputs {
                 pulses.push_back(Pulse {
                     src: cur_pulse.dest,
@@ -164,14 +164,14 @@ fn press_button<F: FnMut(&Pulse) -> bool>(
                     height: new_height,
                 });
             }
-        });
+        }
         // put back what we took
         modules[cur_pulse.dest as usize] = cur_module;
     }
     false
 }
 
-fn part1(data: &Parsed) -> u64 {
+pub fn part1(data: &Parsed) -> u64 {
     let mut modules = data.to_owned();
     let mut pulses = VecDeque::<Pulse>::new();
     let mut low_pulses = 0;
@@ -188,10 +188,10 @@ fn part1(data: &Parsed) -> u64 {
     for _ in 0..1000 {
         press_button(&mut modules, &mut pulses, &mut f);
     }
-    return (low_pulses + 1000) * high_pulses;
+    (low_pulses + 1000) * high_pulses
 }
 
-fn part2(data: &Parsed) -> u64 {
+pub fn part2(data: &Parsed) -> u64 {
     let mut modules = data.to_owned();
     let mut pulses = VecDeque::<Pulse>::new();
 
@@ -232,6 +232,24 @@ fn part2(data: &Parsed) -> u64 {
     }
 }
 
+pub const DAY: Day = Day::new::<Day20>(20);
+
+struct Day20;
+
+impl Solution for Day20 {
+    type Parsed<'a> = Parsed;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     const TEST_INPUT1: &str = "\
@@ -242,37 +260,10 @@ broadcaster -> a, b, c
 &inv -> a
 ";
 
-    use crate::*;
+    use super::*;
     #[test]
     fn test_part1() {
         let data = parse(TEST_INPUT1);
         assert_eq!(32000000, part1(&data));
     }
 }
-
-fn benchmark(s: &str) {
-    let options = Options::default();
-    microbench::bench(&options, "parsing", || {
-        parse(&s);
-    });
-    let data = parse(&s);
-    microbench::bench(&options, "part1", || {
-        part1(&data);
-    });
-    microbench::bench(&options, "part2", || {
-        part2(&data);
-    });
-    microbench::bench(&options, "combined", || {
-        let data = parse(&s);
-        part1(&data);
-        part2(&data);
-    });
-}
-
-fn main() {
-    let s = read_aoc!();
-    let data = parse(&s);
-    println!("{:?}", part1(&data));
-    println!("{:?}", part2(&data));
-    benchmark(&s);
-}
@@ -0,0 +1,99 @@
+use std::fmt::Display;
+use utils::*;
+use std::collections::VecDeque;
+
+pub type Parsed = (Grid<bool, 2>, Coord<2>);
+
+pub fn parse(input: &str) -> Parsed {
+    let (grid, markers) = Grid::parse_with_markers(input, b"S", |b, _| match b {
+        b'#' => Ok(true),
+        b'.' | b'S' => Ok(false),
+        b => Err(UnknownCell(b)),
+    })
+    .unwrap();
+    (grid, markers.first(b'S').unwrap())
+}
+
+pub fn part1(data: &Parsed, max_steps: u32) -> usize {
+    let (is_stone, start) = data;
+    let mut visited = Grid::new(3u8, &is_stone.get_dims());
+    let mut stack = VecDeque::from([(start.to_owned(), 0)]);
+
+    while let Some((coord, steps)) = stack.pop_front() {
+        if visited.get(coord) != Some(&3) || is_stone[coord] || steps > max_steps {
+            continue;
+        }
+        visited[coord] = (steps % 2) as u8;
+        stack.extend(is_stone.neighbors4(coord).map(|(n, _)| (n, steps + 1)));
+    }
+
+    // for row in 0..visited.get_dims()[1] {
+    //     let s: String = (0..visited.get_dims()[0]).map(|col| 
+    //         if is_stone[[col, row]] {
+    //             '#'
+    //         }
+    //         else if visited[[col, row]] == 0 {
+    //             'O'
+    //         }
+    //         else if visited[[col, row]] == 3 {
+    //             '!'
+    //         }
+    //         else {
+    //             '.'
+    //         }
+    //     ).collect();
+    //     println!("{}", s);
+
+    // }
+
+
+    visited.get_data().iter().filter(|x| **x == 0).count()
+}
+
+pub fn part2(_data: &Parsed) -> i64 {
+    0
+}
+
+pub const DAY: Day = Day::new::<Day21>(21);
+
+struct Day21;
+
+impl Solution for Day21 {
+    type Parsed<'a> = Parsed;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data, 64)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    const TEST_INPUT: &str = "\
+ ...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+";
+    use super::*;
+    #[test]
+    fn test_part1() {
+        assert_eq!(16, part1(&parse(TEST_INPUT), 6));
+    }
+    // #[test]
+    // fn test_part2() {
+    //     assert_eq!(0, part1(&parse(TEST_INPUT)));
+    // }
+}
@@ -1,16 +1,16 @@
 use std::cmp::max;
+use std::fmt::Display;
 
-use microbench::{self, Options};
 use utils::*;
 
-type Parsed = Vec<(Coord<3>, Coord<3>)>;
+pub type Parsed = Vec<(Coord<3>, Coord<3>)>;
 
 fn make_coord(s: &str) -> Coord<3> {
     let mut it = s.split(',');
     std::array::from_fn(|_| it.next().unwrap().parse().unwrap()).into()
 }
 
-fn parse(input: &str) -> Parsed {
+pub fn parse(input: &str) -> Parsed {
     let mut bricks = input
         .split_terminator('\n')
         .map(|line| {
@@ -81,9 +81,9 @@ impl Solver for Part1Solver {
     }
 }
 
-fn part1(bricks: &Parsed) -> usize {
+pub fn part1(bricks: &Parsed) -> usize {
     let mut solver = Part1Solver{safe_to_disintegrate: vec![true; bricks.len()]};
-    solve(&bricks, &mut solver);
+    solve(bricks, &mut solver);
 
     solver.safe_to_disintegrate.iter().filter(|x| **x).count()
 }
@@ -117,9 +117,9 @@ impl Solver for Part2Solver {
     }
 }
 
-fn part2(bricks: &Parsed) -> usize {
+pub fn part2(bricks: &Parsed) -> usize {
     let mut solver = Part2Solver{parents: vec![-1i16; bricks.len()]};
-    solve(&bricks, &mut solver);
+    solve(bricks, &mut solver);
 
     let mut num_fallen = vec![0; bricks.len()];
     let mut sum_fallen = 0;
@@ -133,6 +133,24 @@ fn part2(bricks: &Parsed) -> usize {
     sum_fallen
 }
 
+pub const DAY: Day = Day::new::<Day22>(22);
+
+struct Day22;
+
+impl Solution for Day22 {
+    type Parsed<'a> = Parsed;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     const TEST_INPUT: &str = "\
@@ -144,7 +162,7 @@ mod tests {
 0,1,6~2,1,6
 1,1,8~1,1,9
 ";
-    use crate::*;
+    use super::*;
     #[test]
     fn test_part1() {
         assert_eq!(5, part1(&parse(TEST_INPUT)));
@@ -154,30 +172,3 @@ mod tests {
         assert_eq!(7, part2(&parse(TEST_INPUT)));
     }
 }
-
-fn benchmark(s: &str) {
-    let options = Options::default();
-    microbench::bench(&options, "parsing", || {
-        parse(&s);
-    });
-    let data = parse(&s);
-    microbench::bench(&options, "part1", || {
-        part1(&data);
-    });
-    microbench::bench(&options, "part2", || {
-        part2(&data);
-    });
-    microbench::bench(&options, "combined", || {
-        let data = parse(&s);
-        part1(&data);
-        part2(&data);
-    });
-}
-
-fn main() {
-    let s = read_aoc!();
-    let data = parse(&s);
-    println!("{:?}", part1(&data));
-    println!("{:?}", part2(&data));
-    benchmark(&s);
-}
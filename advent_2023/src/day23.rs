@@ -1,17 +1,17 @@
-use microbench::{self, Options};
 use std::collections::hash_map;
+use std::fmt::Display;
 use utils::*;
 
 #[derive(Debug, Clone, Copy)]
-enum Entry {
+pub enum Entry {
     Path,
     Forest,
     Slope([i8; 2]),
 }
 
-type Parsed = Grid<Entry, 2>;
+pub type Parsed = Grid<Entry, 2>;
 
-fn parse(input: &str) -> Parsed {
+pub fn parse(input: &str) -> Parsed {
     let width = input.find('\n').unwrap() as i64;
     let height = input.len() as i64 / (width + 1);
     let mut grid = Grid::new(Entry::Forest, &[width, height]);
@@ -156,16 +156,34 @@ fn max_graph_path(nodes: &[Node]) -> u64 {
     max_path
 }
 
-fn part1(data: &Parsed) -> u64 {
+pub fn part1(data: &Parsed) -> u64 {
     let d = make_graph::<true>(data);
     max_graph_path(&d) - 1
 }
 
-fn part2(data: &Parsed) -> u64 {
+pub fn part2(data: &Parsed) -> u64 {
     let d = make_graph::<false>(data);
     max_graph_path(&d) - 1
 }
 
+pub const DAY: Day = Day::new::<Day23>(23);
+
+struct Day23;
+
+impl Solution for Day23 {
+    type Parsed<'a> = Parsed;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     const TEST_INPUT: &str = "\
@@ -193,7 +211,7 @@ mod tests {
 #.....###...###...#...#
 #####################.#   
 ";
-    use crate::*;
+    use super::*;
     #[test]
     fn test_part1() {
         let x = make_graph::<true>(&parse(TEST_INPUT));
@@ -211,30 +229,3 @@ mod tests {
         assert_eq!(154, part2(&parse(TEST_INPUT)));
     }
 }
-
-fn benchmark(s: &str) {
-    let options = Options::default();
-    microbench::bench(&options, "parsing", || {
-        parse(&s);
-    });
-    let data = parse(&s);
-    microbench::bench(&options, "part1", || {
-        part1(&data);
-    });
-    microbench::bench(&options, "part2", || {
-        part2(&data);
-    });
-    microbench::bench(&options, "combined", || {
-        let data = parse(&s);
-        part1(&data);
-        part2(&data);
-    });
-}
-
-fn main() {
-    let s = read_aoc!();
-    let data = parse(&s);
-    println!("{:?}", part1(&data));
-    println!("{:?}", part2(&data));
-    benchmark(&s);
-}
@@ -1,17 +1,17 @@
-use std::ptr::null_mut;
 
 use microbench::{self, Options};
+use std::fmt::Display;
 use utils::*;
 
 type HailStone = ([i64; 3], [i64; 3]);
-type Parsed = Vec<HailStone>;
+pub type Parsed = Vec<HailStone>;
 
 fn make_triple(s: &str) -> [i64; 3] {
     let mut it = s.split(", ");
     std::array::from_fn(|_| it.next().unwrap().parse().unwrap())
 }
 
-fn parse(input: &str) -> Parsed {
+pub fn parse(input: &str) -> Parsed {
     input
         .split_terminator('\n')
         .map(|line| {
@@ -46,7 +46,7 @@ fn intersect_stones_2d(first: &HailStone, second: &HailStone) -> Option<[f64; 2]
     Some([x, y])
 }
 
-fn part1(data: &Parsed) -> u64 {
+pub fn part1(data: &Parsed) -> u64 {
     let search_area = 200000000000000.0..=400000000000000.0;
     let mut num_intersections = 0;
 
@@ -63,14 +63,65 @@ fn part1(data: &Parsed) -> u64 {
     num_intersections
 }
 
-fn part2(data: &Parsed) -> i64 {
+pub fn part2(_data: &Parsed) -> i64 {
     0
 }
 
+fn checked(b: &[u8]) -> i64 {
+    // hello
+    std::str::from_utf8(b).unwrap().parse().unwrap()
+}
+fn unchecked(b: &[u8]) -> i64 {
+    unsafe { std::str::from_utf8_unchecked(b) }.parse().unwrap()
+}
+
+#[allow(dead_code)]
+fn benchmark_int_parsing(s: &str) {
+    let options = Options::default();
+    let strs: Vec<_> = s
+        .split_terminator('\n')
+        .flat_map(|line| {
+            let (p_str, v_str) = line.split_once(" @ ").unwrap();
+             let mut v = Vec::with_capacity(6);
+             v.extend(p_str.split(", "));
+             v.extend(v_str.split(", "));
+             v
+        })
+        .map(|s| s.as_bytes()).collect();
+    microbench::bench(&options, "checked", || {
+        strs.iter().map(|b| {
+            checked(b)
+        }).sum::<i64>()
+    });
+    microbench::bench(&options, "unchecked", || {
+        strs.iter().map(|b| {
+            unchecked(b)
+        }).sum::<i64>()
+    });
+}
+
+pub const DAY: Day = Day::new::<Day24>(24);
+
+struct Day24;
+
+impl Solution for Day24 {
+    type Parsed<'a> = Parsed;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     const TEST_INPUT: &str = "";
-    use crate::*;
+    use super::*;
     #[test]
     fn test_part1() {
         // intersect
@@ -103,63 +154,16 @@ mod tests {
             intersect_stones_2d(&([18, 19, 22], [-1, -3, -2]), &([20, 25, 34], [-2, -6, -4]))
         );
     }
+    #[test]
+    fn test_int_parsers_agree() {
+        Differential::new(|rng| rng.range(-1_000_000_000_000..=1_000_000_000_000).to_string())
+            .variant("checked", |s| checked(s.as_bytes()))
+            .variant("unchecked", |s| unchecked(s.as_bytes()))
+            .assert_agree();
+    }
+
     #[test]
     fn test_part2() {
         assert_eq!(0, part1(&parse(TEST_INPUT)));
     }
 }
-
-fn checked(b: &[u8]) -> i64 {
-    // hello
-    std::str::from_utf8(b).unwrap().parse().unwrap()
-}
-fn unchecked(b: &[u8]) -> i64 {
-    unsafe { std::str::from_utf8_unchecked(b) }.parse().unwrap()
-}
-
-fn benchmark(s: &str) {
-    let options = Options::default();
-    // microbench::bench(&options, "parsing", || {
-    //     parse(&s);
-    // });
-    // let data = parse(&s);
-    // microbench::bench(&options, "part1", || {
-    //     part1(&data)
-    // });
-    // microbench::bench(&options, "part2", || {
-    //     part2(&data);
-    // });
-    // microbench::bench(&options, "combined", || {
-    //     let data = parse(&s);
-    //     part1(&data);
-    //     part2(&data);
-    // });
-    let strs: Vec<_> = s
-        .split_terminator('\n')
-        .flat_map(|line| {
-            let (p_str, v_str) = line.split_once(" @ ").unwrap();
-             let mut v = Vec::with_capacity(6);
-             v.extend(p_str.split(", "));
-             v.extend(v_str.split(", "));
-             v
-        })
-        .map(|s| s.as_bytes()).collect();
-    microbench::bench(&options, "checked", || {
-        strs.iter().map(|b| {
-            checked(b)
-        }).sum::<i64>()
-    });
-    microbench::bench(&options, "unchecked", || {
-        strs.iter().map(|b| {
-            unchecked(b)
-        }).sum::<i64>()
-    });
-}
-
-fn main() {
-    let s = read_aoc!();
-    // let data = parse(&s);
-    // println!("{:?}", part1(&data));
-    // println!("{:?}", part2(&data));
-    benchmark(&s);
-}
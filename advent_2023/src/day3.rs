@@ -1,15 +1,16 @@
+use std::fmt::Display;
 use utils::*;
-use microbench::{self, Options};
 
 #[derive(Debug, Clone, Copy)]
-enum Entry {
+pub enum Entry {
     Nothing,
     Part,
     MaybeGear,
     Number(usize),
 }
 
-fn parse(input: &str) -> (Grid<Entry, 2>, Vec<i64>) {
+#[allow(clippy::is_digit_ascii_radix, clippy::chars_next_cmp)]
+pub fn parse(input: &str) -> (Grid<Entry, 2>, Vec<i64>) {
     let width = input.find('\n').unwrap() as i64;
     let height = (input.len() as i64) / (width + 1);
     let mut grid = Grid::new(Entry::Nothing, &[width, height]);
@@ -42,19 +43,7 @@ fn parse(input: &str) -> (Grid<Entry, 2>, Vec<i64>) {
     (grid, values)
 }
 
-// In this order to simplify part 2; this order of neighbors results in non-decreasing id's of Numbers
-const NEIGHBORS: [[i64; 2]; 8] = [
-    [-1, -1],
-    [0, -1],
-    [1, -1],
-    [-1, 0],
-    [1, 0],
-    [-1, 1],
-    [0, 1],
-    [1, 1],
-];
-
-fn part1((grid, values): &(Grid<Entry, 2>, Vec<i64>)) -> i64 {
+pub fn part1((grid, values): &(Grid<Entry, 2>, Vec<i64>)) -> i64 {
     let [width, height] = grid.get_dims();
 
     let mut numbers = HashSet::new();
@@ -65,8 +54,8 @@ fn part1((grid, values): &(Grid<Entry, 2>, Vec<i64>)) -> i64 {
             if !matches!(grid[c], Entry::MaybeGear | Entry::Part) {
                 continue;
             }
-            for n in NEIGHBORS {
-                if let Some(Entry::Number(id)) = grid.get(c + n) {
+            for (_, entry) in grid.neighbors8(c) {
+                if let Entry::Number(id) = entry {
                     numbers.insert(*id);
                 }
             }
@@ -75,9 +64,9 @@ fn part1((grid, values): &(Grid<Entry, 2>, Vec<i64>)) -> i64 {
     numbers.iter().map(|id| values[*id]).sum()
 }
 
-fn part2((grid, values): &(Grid<Entry, 2>, Vec<i64>)) -> i64 {
+pub fn part2((grid, values): &(Grid<Entry, 2>, Vec<i64>)) -> i64 {
     let [width, height] = grid.get_dims();
-    let mut numbers = Vec::with_capacity(NEIGHBORS.len());
+    let mut numbers = Vec::with_capacity(8);
     let mut result = 0;
 
     for row_index in 0..height {
@@ -86,8 +75,10 @@ fn part2((grid, values): &(Grid<Entry, 2>, Vec<i64>)) -> i64 {
             if !matches!(grid[c], Entry::MaybeGear) {
                 continue;
             }
-            for n in NEIGHBORS {
-                if let Some(Entry::Number(id)) = grid.get(c + n) {
+            // Reading order gives each number's id in a non-decreasing run, so
+            // only the last one needs checking for duplicates.
+            for (_, entry) in grid.neighbors8(c) {
+                if let Entry::Number(id) = entry {
                     if Some(id) != numbers.last() {
                         numbers.push(*id);
                     }
@@ -102,6 +93,24 @@ fn part2((grid, values): &(Grid<Entry, 2>, Vec<i64>)) -> i64 {
     result
 }
 
+pub const DAY: Day = Day::new::<Day3>(3);
+
+struct Day3;
+
+impl Solution for Day3 {
+    type Parsed<'a> = (Grid<Entry, 2>, Vec<i64>);
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -118,7 +127,7 @@ mod tests {
 .664.598..
 ";
 
-    use crate::*;
+    use super::*;
     #[test]
     fn test_part1() {
         let result = part1(&parse(TEST_INPUT));
@@ -129,20 +138,3 @@ mod tests {
         assert_eq!(467835, part2(&parse(TEST_INPUT)));
     }
 }
-
-fn benchmark(s: &str) {
-    let options = Options::default();
-    microbench::bench(&options, "combined", || {
-        let data = parse(&s);
-        part1(&data);
-        part2(&data);
-    });
-}
-
-fn main() {
-    let s = read_aoc!();
-    let data = parse(&s);
-    println!("{:?}", part1(&data));
-    println!("{:?}", part2(&data));
-    benchmark(&s);
-}
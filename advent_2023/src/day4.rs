@@ -1,5 +1,5 @@
-use microbench::{self, Options};
 use std::cmp::min;
+use std::fmt::Display;
 use utils::*;
 
 fn count_matches(line: &str, set: &mut HashSet<i32>) -> usize {
@@ -17,7 +17,7 @@ fn count_matches(line: &str, set: &mut HashSet<i32>) -> usize {
         .count()
 }
 
-fn parse(input: &str) -> Vec<usize> {
+pub fn parse(input: &str) -> Vec<usize> {
     let mut set = HashSet::new();
     input
         .split_terminator('\n')
@@ -25,7 +25,7 @@ fn parse(input: &str) -> Vec<usize> {
         .collect()
 }
 
-fn part1(matches: &[usize]) -> usize {
+pub fn part1(matches: &[usize]) -> usize {
     matches
         .iter()
         .map(|m| {
@@ -38,7 +38,7 @@ fn part1(matches: &[usize]) -> usize {
         .sum()
 }
 
-fn part2(matches: &[usize]) -> usize {
+pub fn part2(matches: &[usize]) -> usize {
     let mut copies = vec![0; matches.len()];
     let mut total_cards = 0;
     for (i, &m) in matches.iter().enumerate() {
@@ -51,6 +51,24 @@ fn part2(matches: &[usize]) -> usize {
     total_cards
 }
 
+pub const DAY: Day = Day::new::<Day4>(4);
+
+struct Day4;
+
+impl Solution for Day4 {
+    type Parsed<'a> = Vec<usize>;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     const TEST_INPUT: &str = "\
@@ -61,7 +79,7 @@ Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
 Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
 Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
 ";
-    use crate::*;
+    use super::*;
     #[test]
     fn test_part1() {
         assert_eq!(13, part1(&parse(TEST_INPUT)));
@@ -71,30 +89,3 @@ Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
         assert_eq!(30, part2(&parse(TEST_INPUT)));
     }
 }
-
-fn benchmark(s: &str) {
-    let options = Options::default();
-    microbench::bench(&options, "parsing", || {
-        parse(&s);
-    });
-    let data = parse(&s);
-    microbench::bench(&options, "part1", || {
-        part1(&data);
-    });
-    microbench::bench(&options, "part2", || {
-        part2(&data);
-    });
-    microbench::bench(&options, "combined", || {
-        let data = parse(&s);
-        part1(&data);
-        part2(&data);
-    });
-}
-
-fn main() {
-    let s = read_aoc!();
-    let data = parse(&s);
-    println!("{:?}", part1(&data));
-    println!("{:?}", part2(&data));
-    benchmark(&s);
-}
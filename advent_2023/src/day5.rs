@@ -1,3 +1,4 @@
+use std::fmt::Display;
 use utils::*;
 
 use std::{
@@ -5,7 +6,6 @@ use std::{
     ops::Range,
 };
 
-use microbench::{self, Options};
 
 #[derive(Debug, Clone, Copy)]
 struct MapRange {
@@ -15,7 +15,7 @@ struct MapRange {
 }
 
 #[derive(Debug)]
-struct Map {
+pub struct Map {
     ranges: Vec<MapRange>,
 }
 
@@ -24,8 +24,8 @@ impl Map {
         it.next().unwrap(); // skip first line
         let mut ranges = vec![];
         let mut more = false;
-        while let Some(line) = it.next() {
-            if line == "" {
+        for line in it.by_ref() {
+            if line.is_empty() {
                 more = true;
                 break;
             }
@@ -97,7 +97,7 @@ impl Map {
     }
 }
 
-fn parse(input: &str) -> (Vec<u64>, Vec<Map>) {
+pub fn parse(input: &str) -> (Vec<u64>, Vec<Map>) {
     let mut lines = input.split_terminator('\n');
     let seeds = lines
         .next()
@@ -119,14 +119,11 @@ fn parse(input: &str) -> (Vec<u64>, Vec<Map>) {
     (seeds, maps)
 }
 
-fn map_seed(seed: u64, maps: &Vec<Map>) -> u64 {
-    maps.iter().fold(seed, |acc, e| {
-        let x = e.map(acc);
-        x
-    })
+fn map_seed(seed: u64, maps: &[Map]) -> u64 {
+    maps.iter().fold(seed, |acc, e| e.map(acc))
 }
 
-fn part1(data: &(Vec<u64>, Vec<Map>)) -> u64 {
+pub fn part1(data: &(Vec<u64>, Vec<Map>)) -> u64 {
     let (seeds, maps) = data;
     seeds.iter().map(|s| map_seed(*s, maps)).min().unwrap()
 }
@@ -135,9 +132,9 @@ fn seeds_to_ranges(seeds: &[u64]) -> Vec<Range<u64>> {
     seeds.chunks_exact(2).map(|x| x[0]..(x[0] + x[1])).to_vec()
 }
 
-fn part2(data: &(Vec<u64>, Vec<Map>)) -> u64 {
+pub fn part2(data: &(Vec<u64>, Vec<Map>)) -> u64 {
     let (seeds, maps) = data;
-    let mut input = seeds_to_ranges(&seeds);
+    let mut input = seeds_to_ranges(seeds);
     input.sort_unstable_by_key(|x| x.start);
     let mut output = vec![];
     for map in maps {
@@ -157,6 +154,24 @@ fn part2(data: &(Vec<u64>, Vec<Map>)) -> u64 {
     output.first().unwrap().start
 }
 
+pub const DAY: Day = Day::new::<Day5>(5);
+
+struct Day5;
+
+impl Solution for Day5 {
+    type Parsed<'a> = (Vec<u64>, Vec<Map>);
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     const TEST_INPUT: &str = "\
@@ -194,7 +209,7 @@ humidity-to-location map:
 60 56 37
 56 93 4   
 ";
-    use crate::*;
+    use super::*;
     #[test]
     fn test_part1() {
         assert_eq!(35, part1(&parse(TEST_INPUT)));
@@ -217,29 +232,7 @@ humidity-to-location map:
     fn test_light_to_temp() {
         let (_seeds, maps) = parse(TEST_INPUT);
         let mut output = vec![];
-        maps[4].map_ranges(&[77..78], &mut output);
+        maps[4].map_ranges(std::slice::from_ref(&(77..78)), &mut output);
         assert_eq!(vec![45..46], output);
     }
 }
-
-fn benchmark(s: &str) {
-    let options = Options::default();
-    microbench::bench(&options, "parsing", || {
-        parse(&s);
-    });
-    let data = parse(&s);
-    microbench::bench(&options, "part1", || {
-        part1(&data);
-    });
-    microbench::bench(&options, "part2", || {
-        part2(&data);
-    });
-}
-
-fn main() {
-    let s = read_aoc!();
-    let data = parse(&s);
-    println!("{:?}", part1(&data));
-    println!("{:?}", part2(&data));
-    benchmark(&s);
-}
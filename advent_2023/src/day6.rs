@@ -1,13 +1,13 @@
+use std::fmt::Display;
 use utils::*;
-use microbench::{self, Options};
 
 #[derive(Debug, Clone, Copy)]
-struct Race {
+pub struct Race {
     time: u64,
     distance: u64,
 }
 
-fn parse(input: &str) -> Vec<Race> {
+pub fn parse(input: &str) -> Vec<Race> {
     let mut lines = input.split_terminator('\n');
     let times = lines
         .next()
@@ -33,14 +33,14 @@ fn parse(input: &str) -> Vec<Race> {
 fn num_winners(r: Race) -> u64 {
     let pm = ((r.time * r.time - 4 * r.distance) as f64).sqrt() / 2.0;
 
-    if r.time % 2 == 0 {
+    if r.time.is_multiple_of(2) {
         1 + 2 * ((pm - 1.0).ceil() as u64)
     } else {
         2 * ((pm - 0.5).ceil()) as u64
     }
 }
 
-fn part1(data: &[Race]) -> u64 {
+pub fn part1(data: &[Race]) -> u64 {
     data.iter().fold(1, |acc, r| acc * num_winners(*r))
 }
 
@@ -49,7 +49,7 @@ fn combine(left: u64, right: u64) -> u64 {
     left * 10u64.pow(right_digits as u32) + right
 }
 
-fn part2(data: &[Race]) -> u64 {
+pub fn part2(data: &[Race]) -> u64 {
     let time = data[1..]
         .iter()
         .fold(data[0].time, |acc, e| combine(acc, e.time));
@@ -59,9 +59,27 @@ fn part2(data: &[Race]) -> u64 {
     num_winners(Race { time, distance })
 }
 
+pub const DAY: Day = Day::new::<Day6>(6);
+
+struct Day6;
+
+impl Solution for Day6 {
+    type Parsed<'a> = Vec<Race>;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
     #[test]
     fn test_num_winners() {
         assert_eq!(
@@ -87,23 +105,3 @@ mod tests {
         );
     }
 }
-
-fn benchmark(s: &str) {
-    let options = Options::default();
-    microbench::bench(&options, "parsing", || {
-        parse(&s);
-    });
-    // part1 seems too fast to bench on its own with default options
-    microbench::bench(&options, "combined", || {
-        let data = parse(&s);
-        part1(&data);
-        part2(&data);
-    });
-}
-fn main() {
-    let s = read_aoc!();
-    let data = parse(&s);
-    println!("{:?}", part1(&data));
-    println!("{:?}", part2(&data));
-    benchmark(&s);
-}
@@ -1,8 +1,8 @@
+use std::fmt::Display;
 use utils::*;
-use microbench::{self, Options};
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
-struct Hand {
+pub struct Hand {
     cards: [u8; 5],
     bid: usize,
 }
@@ -18,7 +18,7 @@ fn get_card(c: char) -> u8 {
     }
 }
 
-fn parse(input: &str) -> Vec<Hand> {
+pub fn parse(input: &str) -> Vec<Hand> {
     input
         .split_terminator('\n')
         .map(|line| {
@@ -54,13 +54,13 @@ fn hand_type(h: &Hand) -> u8 {
     }
 }
 
-fn part1(data: &[Hand]) -> usize {
+pub fn part1(data: &[Hand]) -> usize {
     let mut v = data.iter().map(|h| (hand_type(h), h.clone())).to_vec();
     v.sort_unstable();
     v.iter().enumerate().map(|(i, h)| (i + 1) * h.1.bid).sum()
 }
 
-fn part2(data: &[Hand]) -> usize {
+pub fn part2(data: &[Hand]) -> usize {
     let mut v = data
         .iter()
         .map(|h| {
@@ -77,29 +77,20 @@ fn part2(data: &[Hand]) -> usize {
     v.iter().enumerate().map(|(i, h)| (i + 1) * h.1.bid).sum()
 }
 
-fn benchmark(s: &str) {
-    let options = Options::default();
-    microbench::bench(&options, "parsing", || {
-        parse(&s);
-    });
-    let data = parse(&s);
-    microbench::bench(&options, "part1", || {
-        part1(&data);
-    });
-    microbench::bench(&options, "part2", || {
-        part2(&data);
-    });
-    microbench::bench(&options, "combined", || {
-        let data = parse(&s);
-        part1(&data);
-        part2(&data);
-    });
-}
+pub const DAY: Day = Day::new::<Day7>(7);
+
+struct Day7;
+
+impl Solution for Day7 {
+    type Parsed<'a> = Vec<Hand>;
 
-fn main() {
-    let s = read_aoc!();
-    let data = parse(&s);
-    println!("{:?}", part1(&data));
-    println!("{:?}", part2(&data));
-    benchmark(&s);
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
 }
@@ -1,4 +1,4 @@
-use microbench::{self, Options};
+use std::fmt::Display;
 use utils::*;
 
 type Index = u16;
@@ -8,7 +8,7 @@ struct Node {
     neighbors: [Index; 2],
 }
 
-struct Parsed {
+pub struct Parsed {
     dirs: Vec<u8>,
     nodes: Vec<Node>,
     start: Index,
@@ -17,7 +17,7 @@ struct Parsed {
     ends: Vec<Index>,
 }
 
-fn parse(input: &str) -> Parsed {
+pub fn parse(input: &str) -> Parsed {
     let mut it = input.split_terminator('\n');
     let dirs = it
         .next()
@@ -65,7 +65,7 @@ fn parse(input: &str) -> Parsed {
     }
 }
 
-fn part1(data: &Parsed) -> u64 {
+pub fn part1(data: &Parsed) -> u64 {
     let mut total_steps = 0;
     let mut dir_index = 0;
     let mut cur_index = data.start;
@@ -103,7 +103,7 @@ fn find_end(start: Index, nodes: &[Node], dirs: &[u8], ends: &[bool]) -> u64 {
     }
 }
 
-fn part2(data: &Parsed) -> u64 {
+pub fn part2(data: &Parsed) -> u64 {
     let mut ends_bits = vec![false; data.nodes.len()];
     for e in &data.ends {
         ends_bits[*e as usize] = true;
@@ -116,29 +116,20 @@ fn part2(data: &Parsed) -> u64 {
             .fold(1, |acc, e| acc * (e / data.dirs.len() as u64))
 }
 
-fn benchmark(s: &str) {
-    let options = Options::default();
-    microbench::bench(&options, "parsing", || {
-        parse(&s);
-    });
-    let data = parse(&s);
-    microbench::bench(&options, "part1", || {
-        part1(&data);
-    });
-    microbench::bench(&options, "part2", || {
-        part2(&data);
-    });
-    microbench::bench(&options, "combined", || {
-        let data = parse(&s);
-        part1(&data);
-        part2(&data);
-    });
-}
+pub const DAY: Day = Day::new::<Day8>(8);
+
+struct Day8;
+
+impl Solution for Day8 {
+    type Parsed<'a> = Parsed;
 
-fn main() {
-    let s = read_aoc!();
-    let data = parse(&s);
-    println!("{:?}", part1(&data));
-    println!("{:?}", part2(&data));
-    benchmark(&s);
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
 }
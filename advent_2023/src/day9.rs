@@ -0,0 +1,65 @@
+use std::fmt::Display;
+use utils::*;
+
+type Num = i32;
+
+pub fn parse(input: &str) -> Vec<Vec<Num>> {
+    input
+        .split_terminator('\n')
+        .map(|line| line.split_whitespace().map(|x| x.parse().unwrap()).to_vec())
+        .to_vec()
+}
+
+fn process_sequence<const LAST: bool>(x: &[Num]) -> Num {
+    let mut v = x.to_vec();
+    let mut sum = 0;
+    let mut mult = 1;
+
+    loop {
+        if LAST {
+            sum += v.last().unwrap();
+        } else {
+            sum += v.first().unwrap() * mult;
+            mult *= -1;
+        }
+        let mut all_zero = true;
+        for i in 0..(v.len() - 1) {
+            let diff = v[i + 1] - v[i];
+            if diff != 0 {
+                all_zero = false;
+            }
+            v[i] = diff;
+        }
+        v.pop().unwrap();
+        if all_zero {
+            break;
+        }
+    }
+    sum
+}
+
+pub fn part1(data: &[Vec<Num>]) -> Num {
+    data.iter().map(|seq| process_sequence::<true>(seq)).sum()
+}
+
+pub fn part2(data: &[Vec<Num>]) -> Num {
+    data.iter().map(|seq| process_sequence::<false>(seq)).sum()
+}
+
+pub const DAY: Day = Day::new::<Day9>(9);
+
+struct Day9;
+
+impl Solution for Day9 {
+    type Parsed<'a> = Vec<Vec<Num>>;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
+}
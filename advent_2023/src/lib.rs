@@ -0,0 +1,55 @@
+use utils::Day;
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+pub const DAYS: &[Day] = &[
+    day1::DAY,
+    day2::DAY,
+    day3::DAY,
+    day4::DAY,
+    day5::DAY,
+    day6::DAY,
+    day7::DAY,
+    day8::DAY,
+    day9::DAY,
+    day10::DAY,
+    day11::DAY,
+    day12::DAY,
+    day13::DAY,
+    day14::DAY,
+    day15::DAY,
+    day16::DAY,
+    day17::DAY,
+    day18::DAY,
+    day19::DAY,
+    day20::DAY,
+    day21::DAY,
+    day22::DAY,
+    day23::DAY,
+    day24::DAY,
+    day25::DAY,
+];
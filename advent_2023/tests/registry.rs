@@ -0,0 +1,30 @@
+use advent_2023::{day17, DAYS};
+
+#[test]
+fn test_days_in_order() {
+    let numbers: Vec<_> = DAYS.iter().map(|d| d.day).collect();
+    assert_eq!((1..=25).collect::<Vec<_>>(), numbers);
+}
+
+#[test]
+fn test_registry_matches_module() {
+    let input = "\
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533
+";
+    let day = DAYS.iter().find(|d| d.day == 17).unwrap();
+    let data = day17::parse(input);
+    assert_eq!((102, 94), (day17::part1(&data), day17::part2(&data)));
+    assert_eq!(["102".to_string(), "94".to_string()], day.solve(input));
+}
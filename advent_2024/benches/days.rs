@@ -0,0 +1,30 @@
+//! Statistical benchmarks of every day with a cached input, one criterion
+//! group per day and one benchmark per phase. Criterion reports a confidence
+//! interval for each and the change since the last run; `--save-baseline
+//! <name>` and `--baseline <name>` compare against a named run instead.
+//!
+//!     cargo bench -p advent_2024
+//!     cargo bench -p advent_2024 -- day5/
+
+use advent_2024::DAYS;
+use criterion::{criterion_group, criterion_main, Criterion};
+use utils::cached_input;
+
+const YEAR: i16 = 2024;
+
+fn days(c: &mut Criterion) {
+    for day in DAYS {
+        let Some(input) = cached_input(env!("CARGO_MANIFEST_DIR"), YEAR, day.day) else {
+            eprintln!("no input for day {}, skipping", day.day);
+            continue;
+        };
+        let mut group = c.benchmark_group(format!("day{}", day.day));
+        day.phases(&input, &mut |phase, f| {
+            group.bench_function(phase.as_str(), |b| b.iter(&mut *f));
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -1,13 +1,14 @@
+use std::fmt::Display;
 use utils::*;
 
-fn parse(input: &str) -> impl Iterator<Item = (i64, i64)> {
+pub fn parse(input: &str) -> impl Iterator<Item = (i64, i64)> {
     input.split_terminator('\n').map(|line| {
         let (i1, i2) = line.split_once("   ").unwrap();
         (i1.parse().unwrap(), i2.parse().unwrap())
     })
 }
 
-fn part1(input: &str) -> i64 {
+pub fn part1(input: &str) -> i64 {
     let (mut v1, mut v2): (Vec<_>, Vec<_>) = parse(input).unzip();
     v1.sort();
     v2.sort();
@@ -17,7 +18,7 @@ fn part1(input: &str) -> i64 {
         .fold(0, |acc, (i1, i2)| acc + (*i1 - *i2).abs())
 }
 
-fn part2(input: &str) -> i64 {
+pub fn part2(input: &str) -> i64 {
     let mut v1 = vec![];
     let mut h2: HashMap<i64, i64> = HashMap::new();
     parse(input).for_each(|(i1, i2)| {
@@ -28,8 +29,20 @@ fn part2(input: &str) -> i64 {
         .fold(0, |acc, e| acc + e * h2.get(e).unwrap_or(&0))
 }
 
-fn main() {
-    let s = read_aoc!();
-    println!("{}", part1(&s));
-    println!("{}", part2(&s));
+pub const DAY: Day = Day::new::<Day1>(1);
+
+struct Day1;
+
+impl Solution for Day1 {
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
 }
@@ -1,11 +1,11 @@
 use std::mem::replace;
-use microbench::{self, Options};
+use std::fmt::Display;
 
 use utils::*;
 
-type Parsed = Grid<i8, 2>;
+pub type Parsed = Grid<i8, 2>;
 
-fn parse(input: &str) -> Parsed {
+pub fn parse(input: &str) -> Parsed {
     let width = input.find('\n').unwrap() as i64;
     let length = (input.len() as i64) / (width+1);
 
@@ -19,8 +19,6 @@ fn parse(input: &str) -> Parsed {
     grid
 }
 
-const NEIGHBORS: [[i64; 2]; 4] = [[1, 0], [-1, 0], [0, 1], [0, -1]];
-
 fn score_trailheads<F: FnMut(Coord<2>)->bool>(trailhead: Coord<2>, heights: &Grid<i8, 2>, mut f: F) -> i64 {
     if heights[trailhead] != 0 {
         return 0;
@@ -40,9 +38,8 @@ fn score_trailheads<F: FnMut(Coord<2>)->bool>(trailhead: Coord<2>, heights: &Gri
             continue;
         }
 
-        for n in NEIGHBORS {
-            let new_coord = c+n;
-            if heights.get(new_coord) == Some(&(height+1)) {
+        for (new_coord, &h) in heights.neighbors4(c) {
+            if h == height+1 {
                 v.push(new_coord);
             }
         }
@@ -50,7 +47,7 @@ fn score_trailheads<F: FnMut(Coord<2>)->bool>(trailhead: Coord<2>, heights: &Gri
     score
 }
 
-fn part1(data: &Parsed) -> i64 {
+pub fn part1(data: &Parsed) -> i64 {
     let dims = data.get_dims();
     let mut total_score = 0;
     for i in 0..dims[0] {
@@ -64,7 +61,7 @@ fn part1(data: &Parsed) -> i64 {
     total_score
 }
 
-fn part2(data: &Parsed) -> i64 {
+pub fn part2(data: &Parsed) -> i64 {
     let dims = data.get_dims();
     let mut total_score = 0;
     for i in 0..dims[0] {
@@ -77,29 +74,20 @@ fn part2(data: &Parsed) -> i64 {
     total_score
 }
 
-fn benchmark(s: &str) {
-    let options = Options::default();
-    microbench::bench(&options, "parsing", || {
-        parse(&s);
-    });
-    let data = parse(&s);
-    microbench::bench(&options, "part1", || {
-        part1(&data);
-    });
-    microbench::bench(&options, "part2", || {
-        part2(&data);
-    });
-    microbench::bench(&options, "combined", || {
-        let data = parse(&s);
-        part1(&data);
-        part2(&data);
-    });
-}
+pub const DAY: Day = Day::new::<Day10>(10);
+
+struct Day10;
+
+impl Solution for Day10 {
+    type Parsed<'a> = Parsed;
 
-fn main() {
-    let s = read_aoc!();
-    let data = parse(&s);
-    println!("{:?}", part1(&data));
-    println!("{:?}", part2(&data));
-    benchmark(&s);
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
 }
@@ -1,7 +1,7 @@
+use std::fmt::Display;
 use utils::*;
-use microbench::{self, Options};
 
-type Parsed = Vec<i64>;
+pub type Parsed = Vec<i64>;
 
 fn with_cache(stone: i64, blinks: i16, cache: &mut HashMap<(i64, i16), i64>) -> i64 {
     if let Some(cached_result) = cache.get(&(stone, blinks)) {
@@ -21,13 +21,13 @@ fn compute_stones(stone: i64, blinks: i16, cache: &mut HashMap<(i64, i16), i64>)
         return with_cache(1, blinks-1, cache);
     }
     let num_digits = stone.ilog10() + 1;
-    if num_digits % 2 == 0 {
+    if num_digits.is_multiple_of(2) {
         return with_cache(stone / (10i64.pow(num_digits/2)), blinks-1, cache) + with_cache(stone % (10i64.pow(num_digits/2)), blinks-1, cache)
     }
-    return with_cache(stone*2024, blinks-1, cache);
+    with_cache(stone*2024, blinks-1, cache)
 }
 
-fn parse(input: &str) -> Parsed {
+pub fn parse(input: &str) -> Parsed {
     input.split_whitespace().map(|s| s.parse().unwrap()).collect()
 
 }
@@ -37,10 +37,28 @@ fn do_it(data: &Parsed, blinks: i16) -> i64 {
     data.iter().fold(0, |acc, i| acc + compute_stones(*i, blinks, &mut cache))
 }
 
+pub const DAY: Day = Day::new::<Day11>(11);
+
+struct Day11;
+
+impl Solution for Day11 {
+    type Parsed<'a> = Parsed;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        do_it(data, 25)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        do_it(data, 75)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     const TEST_INPUT: &str = "125 17";
-    use crate::*;
+    use super::*;
     #[test]
     fn test_part1() {
         let mut cache = HashMap::new();
@@ -51,25 +69,3 @@ mod tests {
         assert_eq!(55312, do_it(&parse(TEST_INPUT), 25));
     }
 }
-
-fn benchmark(s: &str) {
-    let options = Options::default();
-    microbench::bench(&options, "parsing", || {
-        parse(&s);
-    });
-    let data = parse(&s);
-    microbench::bench(&options, "part1", || {
-        do_it(&data, 25);
-    });
-    microbench::bench(&options, "part2", || {
-        do_it(&data, 75);
-    });
-}
-
-fn main() {
-    let s = read_aoc!();
-    let data = parse(&s);
-    println!("{:?}", do_it(&data, 25));
-    println!("{:?}", do_it(&data, 75));
-    benchmark(&s);
-}
@@ -1,8 +1,9 @@
+use std::fmt::Display;
 use utils::*;
 
-type Parsed = Grid<u8, 2>;
+pub type Parsed = Grid<u8, 2>;
 
-fn parse(input: &str) -> Parsed {
+pub fn parse(input: &str) -> Parsed {
     let width = input.find('\n').unwrap() as i64;
     let height = (input.len() as i64) / (width + 1);
     let mut grid = Grid::new(b'0', &[width, height]);
@@ -15,9 +16,7 @@ fn parse(input: &str) -> Parsed {
     grid
 }
 
-const NEIGHBORS: [[i64; 2]; 4] = [[1, 0], [-1, 0], [0, 1], [0, -1]];
-
-fn part1(data: &Parsed) -> i64 {
+pub fn part1(data: &Parsed) -> i64 {
     let mut grid_ids = Grid::new(-1, &data.get_dims());
     let mut id_sizes = vec![];
     let mut traversal_stack = vec![];
@@ -37,15 +36,15 @@ fn part1(data: &Parsed) -> i64 {
             }
             grid_ids[c] = id_sizes.len() as i32 - 1;
             *id_sizes.last_mut().unwrap() += 1;
-            for n in NEIGHBORS {
-                traversal_stack.push(c + n);
-            }
+            traversal_stack.extend(data.neighbors4(c).map(|(n, _)| n));
         }
     }
     let mut id_perims = vec![0; id_sizes.len()];
     for c in grid_ids.iter_coords() {
         let cur_id = grid_ids[c];
-        for n in NEIGHBORS {
+        // Edges of the map count towards the perimeter too, so this can't
+        // skip neighbours that are off it.
+        for n in von_neumann::<2>() {
             if grid_ids.get(c + n) != Some(&cur_id) {
                 id_perims[cur_id as usize] += 1;
             }
@@ -58,7 +57,7 @@ fn part1(data: &Parsed) -> i64 {
         .fold(0, |acc, (s, p)| acc + s * p)
 }
 
-fn part2(data: &Parsed) -> i64 {
+pub fn part2(data: &Parsed) -> i64 {
     let mut grid_ids = Grid::new(-1, &data.get_dims());
     let mut id_sizes = vec![];
     let mut traversal_stack = vec![];
@@ -79,9 +78,7 @@ fn part2(data: &Parsed) -> i64 {
                 }
                 grid_ids[c] = id_sizes.len() as i32 - 1;
                 *id_sizes.last_mut().unwrap() += 1;
-                for n in NEIGHBORS {
-                    traversal_stack.push(c + n);
-                }
+                traversal_stack.extend(data.neighbors4(c).map(|(n, _)| n));
             }
         }
     }
@@ -131,6 +128,24 @@ fn part2(data: &Parsed) -> i64 {
         .fold(0, |acc, (s, p)| acc + s * p)
 }
 
+pub const DAY: Day = Day::new::<Day12>(12);
+
+struct Day12;
+
+impl Solution for Day12 {
+    type Parsed<'a> = Parsed;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     const TEST_INPUT_1: &str = "\
@@ -146,7 +161,7 @@ OOOOO
 OXOXO
 OOOOO
 ";
-    use crate::*;
+    use super::*;
     #[test]
     fn test_part1() {
         assert_eq!(140, part1(&parse(TEST_INPUT_1)));
@@ -157,10 +172,3 @@ OOOOO
         assert_eq!(80, part2(&parse(TEST_INPUT_1)));
     }
 }
-
-fn main() {
-    let s = read_aoc!();
-    let data = parse(&s);
-    println!("{:?}", part1(&data));
-    println!("{:?}", part2(&data));
-}
@@ -1,14 +1,14 @@
-use microbench::{self, Options};
+use std::fmt::Display;
 use utils::*;
 use std::iter::once;
 
-fn parse(line: &str, storage: &mut Vec<i64>) {
+pub fn parse(line: &str, storage: &mut Vec<i64>) {
     storage.clear();
     storage.extend(line.split(' ').map(|l| l.parse::<i64>().unwrap()));
 }
 
 fn adj_i(i: usize, skip_level: Option<usize>) -> usize {
-    i + (skip_level.map_or(false, |s| s <= i) as usize)
+    i + (skip_level.is_some_and(|s| s <= i) as usize)
 }
 
 fn are_levels_safe(levels: &[i64], skip_level: Option<usize>) -> bool {
@@ -20,24 +20,24 @@ fn are_levels_safe(levels: &[i64], skip_level: Option<usize>) -> bool {
     })
 }
 
-fn part1(input: &str) -> usize {
+pub fn part1(input: &str) -> usize {
     let mut v = vec![];
     input
         .split_terminator('\n')
         .filter(|l| {
-            parse(*l, &mut v);
+            parse(l, &mut v);
             are_levels_safe(&v, None)
         })
         .count()
 }
 
-fn part2(input: &str) -> usize {
+pub fn part2(input: &str) -> usize {
     let mut v = vec![];
     input
         .split_terminator('\n')
         .filter(|l| {
-            parse(*l, &mut v);
-            for skip in once(None).chain((0..v.len()).map(|x| Some(x))) {
+            parse(l, &mut v);
+            for skip in once(None).chain((0..v.len()).map(Some)) {
                 if are_levels_safe(&v, skip) {
                     return true;
                 }
@@ -47,9 +47,27 @@ fn part2(input: &str) -> usize {
         .count()
 }
 
+pub const DAY: Day = Day::new::<Day2>(2);
+
+struct Day2;
+
+impl Solution for Day2 {
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
 
     const TEST_INPUT: &str = "\
 1 2 7 8 9
@@ -63,9 +81,3 @@ mod tests {
         assert_eq!(part2(TEST_INPUT), 2);
     }
 }
-
-fn main() {
-    let s = read_aoc!();
-    println!("{:?}", part1(&s));
-    println!("{:?}", part2(&s));
-}
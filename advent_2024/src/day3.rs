@@ -1,12 +1,13 @@
+use std::fmt::Display;
 use utils::*;
 
-enum Token {
+pub enum Token {
     Do,
     Dont,
     Mul(i64, i64),
 }
 
-fn parse(mut input: &str) -> Vec<Token> {
+pub fn parse(mut input: &str) -> Vec<Token> {
     let mut tokens = vec![];
 
     while !input.is_empty() {
@@ -40,7 +41,7 @@ fn parse(mut input: &str) -> Vec<Token> {
     tokens
 }
 
-fn part1(tokens: &[Token]) -> i64 {
+pub fn part1(tokens: &[Token]) -> i64 {
     tokens.iter().fold(0, |acc, e| {
         let x = if let Token::Mul(x, y) = e {
             x*y
@@ -52,7 +53,7 @@ fn part1(tokens: &[Token]) -> i64 {
     })
 }
 
-fn part2(tokens: &[Token]) -> i64 {
+pub fn part2(tokens: &[Token]) -> i64 {
     let mut mult_enabled = true;
     tokens.iter().fold(0, |acc, e| {
         let x = match e {
@@ -73,9 +74,20 @@ fn part2(tokens: &[Token]) -> i64 {
     })
 }
 
-fn main() {
-    let s = read_aoc!();
-    let parsed = parse(&s);
-    println!("{:?}", part1(&parsed));
-    println!("{:?}", part2(&parsed));
+pub const DAY: Day = Day::new::<Day3>(3);
+
+struct Day3;
+
+impl Solution for Day3 {
+    type Parsed<'a> = Vec<Token>;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
 }
@@ -1,17 +1,8 @@
+use std::fmt::Display;
 use utils::*;
 
-fn parse(input: &str) -> Grid<char, 2> {
-    let width = input.find('\n').unwrap();
-    let height = input.len() / (width + 1);
-    let mut grid = Grid::new('\0', &[width as i64, height as i64]);
-
-    for (row, line) in input.split_terminator('\n').enumerate() {
-        for (col, c) in line.chars().enumerate() {
-            grid[[col as i64, row as i64]] = c;
-        }
-    }
-
-    grid
+pub fn parse(input: &str) -> Grid<char, 2> {
+    Grid::parse(input, |b, _| Ok::<_, UnknownCell>(char::from(b))).unwrap()
 }
 
 const DIRS: [[i64; 2]; 8] = [
@@ -25,7 +16,7 @@ const DIRS: [[i64; 2]; 8] = [
     [-1, -1],
 ];
 
-fn part1(grid: &Grid<char, 2>) -> usize {
+pub fn part1(grid: &Grid<char, 2>) -> usize {
     let dims = grid.get_dims();
     (0..dims[0])
         .flat_map(|col| (0..dims[1]).map(move |row| Coord::from([col, row])))
@@ -45,7 +36,7 @@ fn is_mas(c1: Option<&char>, c2: Option<&char>) -> bool {
     is_ms(c1) && is_ms(c2) && c1 != c2
 }
 
-fn part2(grid: &Grid<char, 2>) -> usize {
+pub fn part2(grid: &Grid<char, 2>) -> usize {
     let dims = grid.get_dims();
     (0..dims[0])
         .flat_map(|col| (0..dims[1]).map(move |row| Coord::from([col, row])))
@@ -59,6 +50,24 @@ fn part2(grid: &Grid<char, 2>) -> usize {
         .count()
 }
 
+pub const DAY: Day = Day::new::<Day4>(4);
+
+struct Day4;
+
+impl Solution for Day4 {
+    type Parsed<'a> = Grid<char, 2>;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     const TEST_INPUT: &str = "\
@@ -73,7 +82,7 @@ SAXAMASAAA
 MAMMMXMMMM
 MXMXAXMASX
 ";
-    use crate::*;
+    use super::*;
     #[test]
     fn test_part1() {
         assert_eq!(18, part1(&parse(TEST_INPUT)));
@@ -84,10 +93,3 @@ MXMXAXMASX
         assert_eq!(9, part2(&parse(TEST_INPUT)));
     }
 }
-
-fn main() {
-    let s = read_aoc!();
-    let data = parse(&s);
-    println!("{:?}", part1(&data));
-    println!("{:?}", part2(&data));
-}
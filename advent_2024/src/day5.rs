@@ -1,10 +1,10 @@
-use std::cmp::Ordering;
 
+use std::fmt::Display;
 use utils::*;
 
-type Parsed = (HashMap<(i64, i64), bool>, Vec<Vec<i64>>);
+pub type Parsed = (HashMap<(i64, i64), bool>, Vec<Vec<i64>>);
 
-fn parse(input: &str) -> Parsed {
+pub fn parse(input: &str) -> Parsed {
     let (first, second) = input.split_once("\n\n").unwrap();
     let mut rules = HashMap::new();
     for line in first.split('\n') {
@@ -33,11 +33,11 @@ fn is_update_correct(update: &[i64], rules: &HashMap<(i64, i64), bool>) -> bool
     true
 }
 
-fn part1(data: &Parsed) -> i64 {
+pub fn part1(data: &Parsed) -> i64 {
     let (rules, updates) = data;
 
     updates.iter().fold(0, |acc, update| {
-        acc + if is_update_correct(update, &rules) {
+        acc + if is_update_correct(update, rules) {
             update[update.len() / 2]
         }
         else {
@@ -46,10 +46,10 @@ fn part1(data: &Parsed) -> i64 {
     })
 }
 
-fn part2(data: &mut Parsed) -> i64 {
+pub fn part2(data: &mut Parsed) -> i64 {
     let (rules, updates) = data;
     updates.iter_mut().fold(0, |acc, update| {
-        if is_update_correct(update, &rules) {
+        if is_update_correct(update, rules) {
             return acc;
         }
         loop {
@@ -70,10 +70,28 @@ fn part2(data: &mut Parsed) -> i64 {
     })
 }
 
+pub const DAY: Day = Day::new::<Day5>(5);
+
+struct Day5;
+
+impl Solution for Day5 {
+    type Parsed<'a> = Parsed;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(&mut data.clone())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     const TEST_INPUT: &str = "";
-    use crate::*;
+    use super::*;
     #[test]
     fn test_part1() {
         assert_eq!(0, part1(&parse(TEST_INPUT)));
@@ -83,10 +101,3 @@ mod tests {
         assert_eq!(0, part1(&parse(TEST_INPUT)));
     }
 }
-
-fn main() {
-    let s = read_aoc!();
-    let mut data = parse(&s);
-    println!("{:?}", part1(&data));
-    println!("{:?}", part2(&mut data));
-}
@@ -1,24 +1,16 @@
+use std::fmt::Display;
 use utils::*;
 
-type Parsed = (Grid<bool, 2>, Coord<2>);
+pub type Parsed = (Grid<bool, 2>, Coord<2>);
 
-fn parse(input: &str) -> Parsed {
-    let width = input.find('\n').unwrap() as i64;
-    let height = (input.len() as i64) / (width + 1);
-    let mut grid = Grid::new(false, &[width, height]);
-    let mut loc = None;
-
-    for (row_index, row) in input.split_terminator('\n').enumerate() {
-        for (col_index, ch) in row.chars().enumerate() {
-            match ch {
-                '#' => grid[[col_index as i64, row_index as i64]] = true,
-                '^' => loc = Some(Coord::from([col_index as i64, row_index as i64])),
-                _ => (),
-            }
-        }
-    }
-
-    (grid, loc.unwrap())
+pub fn parse(input: &str) -> Parsed {
+    let (grid, markers) = Grid::parse_with_markers(input, b"^", |b, _| match b {
+        b'#' => Ok(true),
+        b'.' | b'^' => Ok(false),
+        b => Err(UnknownCell(b)),
+    })
+    .unwrap();
+    (grid, markers.first(b'^').unwrap())
 }
 
 fn traverse_lab(start: Point<i64, 2>, occupied: &Grid<bool, 2>) -> impl Iterator<Item = (Coord<2>, Coord<2>)> {
@@ -29,7 +21,7 @@ fn traverse_lab(start: Point<i64, 2>, occupied: &Grid<bool, 2>) -> impl Iterator
         loop {
             let new_pos = cur_pos + dir;
             match occupied.get(new_pos) {
-                Some(true) => dir = Coord::from([-1 * dir[1], dir[0]]),
+                Some(true) => dir = Coord::from([-dir[1], dir[0]]),
                 Some(false) => {
                     cur_pos = new_pos;
                     return Some((cur_pos, dir));
@@ -40,7 +32,7 @@ fn traverse_lab(start: Point<i64, 2>, occupied: &Grid<bool, 2>) -> impl Iterator
     })
 }
 
-fn part1(data: &Parsed) -> usize {
+pub fn part1(data: &Parsed) -> usize {
     let (occupied, start) = data;
     let mut visited = Grid::new(false, &occupied.get_dims());
     visited[*start] = true;
@@ -50,7 +42,7 @@ fn part1(data: &Parsed) -> usize {
     visited.get_data().iter().filter(|x| **x).count()
 }
 
-fn part2(data: &mut Parsed) -> i64 {
+pub fn part2(data: &mut Parsed) -> i64 {
     let (occupied, start) = data;
     let mut num_ways = 0;
     let dims = occupied.get_dims();
@@ -77,6 +69,24 @@ fn part2(data: &mut Parsed) -> i64 {
     num_ways
 }
 
+pub const DAY: Day = Day::new::<Day6>(6);
+
+struct Day6;
+
+impl Solution for Day6 {
+    type Parsed<'a> = Parsed;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(&mut data.clone())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     const TEST_INPUT: &str = "\
@@ -91,7 +101,7 @@ mod tests {
 #.........
 ......#...
 ";
-    use crate::*;
+    use super::*;
     #[test]
     fn test_part1() {
         assert_eq!(41, part1(&parse(TEST_INPUT)));
@@ -101,10 +111,3 @@ mod tests {
         assert_eq!(6, part2(&mut parse(TEST_INPUT)));
     }
 }
-
-fn main() {
-    let s = read_aoc!();
-    let mut data = parse(&s);
-    println!("{:?}", part1(&data));
-    println!("{:?}", part2(&mut data));
-}
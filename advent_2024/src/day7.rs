@@ -1,7 +1,7 @@
+use std::fmt::Display;
 use utils::*;
-use microbench::{self, Options};
 
-type Parsed = Vec<(i64, Vec<i64>)>;
+pub type Parsed = Vec<(i64, Vec<i64>)>;
 
 fn could_be_true<const THIRD_OPERATOR: bool>(test_value: i64, numbers: &[i64]) -> bool {
     let mut v = vec![(numbers[0], 1)];
@@ -28,7 +28,7 @@ fn could_be_true<const THIRD_OPERATOR: bool>(test_value: i64, numbers: &[i64]) -
     false
 }
 
-fn parse(input: &str) -> Parsed {
+pub fn parse(input: &str) -> Parsed {
     input
         .split_terminator('\n')
         .map(|line| {
@@ -52,14 +52,32 @@ fn do_problem<const THIRD_OPERATOR: bool>(data: &Parsed) -> i64 {
 
 }
 
-fn part1(data: &Parsed) -> i64 {
+pub fn part1(data: &Parsed) -> i64 {
     do_problem::<false>(data)
 }
 
-fn part2(data: &Parsed) -> i64 {
+pub fn part2(data: &Parsed) -> i64 {
     do_problem::<true>(data)
 }
 
+pub const DAY: Day = Day::new::<Day7>(7);
+
+struct Day7;
+
+impl Solution for Day7 {
+    type Parsed<'a> = Parsed;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     const TEST_INPUT: &str = "\
@@ -73,10 +91,10 @@ mod tests {
 21037: 9 7 18 13
 292: 11 6 16 20
 ";
-    use crate::*;
+    use super::*;
     #[test]
     fn test_could_be_true() {
-        assert_eq!(true, could_be_true::<false>(190, &[10, 19]));
+        assert!(could_be_true::<false>(190, &[10, 19]));
     }
 
     #[test]
@@ -88,30 +106,3 @@ mod tests {
         assert_eq!(11387, part2(&parse(TEST_INPUT)));
     }
 }
-
-fn benchmark(s: &str) {
-    let options = Options::default();
-    microbench::bench(&options, "parsing", || {
-        parse(&s);
-    });
-    let data = parse(&s);
-    microbench::bench(&options, "part1", || {
-        part1(&data);
-    });
-    microbench::bench(&options, "part2", || {
-        part2(&data);
-    });
-    microbench::bench(&options, "combined", || {
-        let data = parse(&s);
-        part1(&data);
-        part2(&data);
-    });
-}
-
-fn main() {
-    let s = read_aoc!();
-    let data = parse(&s);
-    println!("{:?}", part1(&data));
-    println!("{:?}", part2(&data));
-    benchmark(&s);
-}
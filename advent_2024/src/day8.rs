@@ -1,12 +1,11 @@
-use core::num;
 use std::mem::replace;
+use std::fmt::Display;
 
-use microbench::{self, Options};
 use utils::*;
 
-type Parsed = (HashMap<char, Vec<Coord<2>>>, [i64; 2]);
+pub type Parsed = (HashMap<char, Vec<Coord<2>>>, [i64; 2]);
 
-fn parse(input: &str) -> Parsed {
+pub fn parse(input: &str) -> Parsed {
     let width = input.find('\n').unwrap() as i64;
     let height = (input.len() as i64) / (width + 1);
 
@@ -34,7 +33,7 @@ fn coord_groups<'a>(
         .flat_map(|v| (0..v.len()).flat_map(move |i| ((i + 1)..v.len()).map(move |j| (v[i], v[j]))))
 }
 
-fn part1(data: &Parsed) -> i64 {
+pub fn part1(data: &Parsed) -> i64 {
     let (antennas, dims) = data;
     let mut antinodes = Grid::new(false, dims);
     let mut num_antinodes = 0;
@@ -43,17 +42,16 @@ fn part1(data: &Parsed) -> i64 {
         let antinode2 = (second - first) + second;
 
         for a in [antinode1, antinode2] {
-            if let Some(x) = antinodes.get_mut(a) {
-                if !replace(x, true) { 
+            if let Some(x) = antinodes.get_mut(a)
+                && !replace(x, true) { 
                     num_antinodes += 1;
                 }
-            }
         }
     }
     num_antinodes
 }
 
-fn part2(data: &Parsed) -> i64 {
+pub fn part2(data: &Parsed) -> i64 {
     let (antennas, dims) = data;
     let mut antinode = Grid::new(false, dims);
     let mut num_antinodes = 0;
@@ -66,7 +64,7 @@ fn part2(data: &Parsed) -> i64 {
             let a = first + (dir * index);
 
             if let Some(x) = antinode.get_mut(a) {
-                if *x == false {
+                if !*x {
                     num_antinodes += 1;
                 }
                 *x = true;
@@ -80,7 +78,7 @@ fn part2(data: &Parsed) -> i64 {
         loop {
             let a = first + (dir * index);
             if let Some(x) = antinode.get_mut(a) {
-                if *x == false {
+                if !*x {
                     num_antinodes += 1;
                 }
                 *x = true;
@@ -93,9 +91,20 @@ fn part2(data: &Parsed) -> i64 {
     num_antinodes
 }
 
-fn main() {
-    let s = read_aoc!();
-    let data = parse(&s);
-    println!("{:?}", part1(&data));
-    println!("{:?}", part2(&data));
+pub const DAY: Day = Day::new::<Day8>(8);
+
+struct Day8;
+
+impl Solution for Day8 {
+    type Parsed<'a> = Parsed;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
 }
@@ -1,10 +1,10 @@
 use std::{cmp::Reverse, collections::BinaryHeap};
+use std::fmt::Display;
 use utils::*;
-use microbench::{self, Options};
 
-type Parsed = Vec<i32>;
+pub type Parsed = Vec<i32>;
 
-fn parse(input: &str) -> Parsed {
+pub fn parse(input: &str) -> Parsed {
     input[..(input.len() - 1)]
         .bytes()
         .map(|b| (b - 48) as i32)
@@ -18,7 +18,7 @@ fn reverse_blocks<'a>(data: &'a [i32]) -> impl Iterator<Item = usize> + 'a {
         .flat_map(|i| std::iter::repeat_n(i, data[i] as usize))
 }
 
-fn part1(data: &Parsed) -> i64 {
+pub fn part1(data: &Parsed) -> i64 {
     let mut id_count = -1;
     let ids: Vec<_> = (0..data.len())
         .map(|x| {
@@ -65,7 +65,7 @@ struct Dense {
     num_blocks: i32,
 }
 
-fn part2(data: &Parsed) -> i64 {
+pub fn part2(data: &Parsed) -> i64 {
     let mut files = vec![];
     let mut cur_pos = 0;
     let mut spaces: [BinaryHeap<Reverse<Dense>>; 9] = Default::default();
@@ -117,10 +117,28 @@ fn part2(data: &Parsed) -> i64 {
     checksum
 }
 
+pub const DAY: Day = Day::new::<Day9>(9);
+
+struct Day9;
+
+impl Solution for Day9 {
+    type Parsed<'a> = Parsed;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     const TEST_INPUT: &str = "2333133121414131402\n";
-    use crate::*;
+    use super::*;
     #[test]
     fn test_part1() {
         assert_eq!(1928, part1(&parse(TEST_INPUT)));
@@ -130,30 +148,3 @@ mod tests {
         assert_eq!(2858, part2(&parse(TEST_INPUT)));
     }
 }
-
-fn benchmark(s: &str) {
-    let options = Options::default();
-    microbench::bench(&options, "parsing", || {
-        parse(&s);
-    });
-    let data = parse(&s);
-    microbench::bench(&options, "part1", || {
-        part1(&data);
-    });
-    microbench::bench(&options, "part2", || {
-        part2(&data);
-    });
-    microbench::bench(&options, "combined", || {
-        let data = parse(&s);
-        part1(&data);
-        part2(&data);
-    });
-}
-
-fn main() {
-    let s = read_aoc!();
-    let data = parse(&s);
-    println!("{:?}", part1(&data));
-    println!("{:?}", part2(&data));
-    // benchmark(&s);
-}
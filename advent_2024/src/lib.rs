@@ -0,0 +1,29 @@
+use utils::Day;
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+
+pub const DAYS: &[Day] = &[
+    day1::DAY,
+    day2::DAY,
+    day3::DAY,
+    day4::DAY,
+    day5::DAY,
+    day6::DAY,
+    day7::DAY,
+    day8::DAY,
+    day9::DAY,
+    day10::DAY,
+    day11::DAY,
+    day12::DAY,
+];
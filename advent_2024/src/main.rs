@@ -0,0 +1,6 @@
+use advent_2024::DAYS;
+use utils::aoc_main;
+
+fn main() -> anyhow::Result<()> {
+    aoc_main(env!("CARGO_MANIFEST_DIR"), 2024, DAYS)
+}
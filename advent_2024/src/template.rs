@@ -0,0 +1,48 @@
+use std::fmt::Display;
+use utils::*;
+
+pub type Parsed = ();
+
+pub fn parse(input: &str) -> Parsed {
+
+}
+
+pub fn part1(data: &Parsed) -> i64 {
+    0
+}
+
+pub fn part2(data: &Parsed) -> i64 {
+    0
+}
+
+pub const DAY: Day = Day::new::<DayN>(N);
+
+struct DayN;
+
+impl Solution for DayN {
+    type Parsed<'a> = Parsed;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+    fn part1(data: &Self::Parsed<'_>) -> impl Display {
+        part1(data)
+    }
+    fn part2(data: &Self::Parsed<'_>) -> impl Display {
+        part2(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    const TEST_INPUT: &str = "";
+    use super::*;
+    #[test]
+    fn test_part1() {
+        assert_eq!(0, part1(&parse(TEST_INPUT)));
+    }
+    #[test]
+    fn test_part2() {
+        assert_eq!(0, part2(&parse(TEST_INPUT)));
+    }
+}
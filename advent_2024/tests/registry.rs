@@ -0,0 +1,15 @@
+use advent_2024::{day1, DAYS};
+
+#[test]
+fn test_days_in_order() {
+    let numbers: Vec<_> = DAYS.iter().map(|d| d.day).collect();
+    assert_eq!((1..=numbers.len() as u32).collect::<Vec<_>>(), numbers);
+}
+
+#[test]
+fn test_registry_matches_module() {
+    let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+    assert_eq!(11, day1::part1(input));
+    assert_eq!(31, day1::part2(input));
+    assert_eq!(["11".to_string(), "31".to_string()], DAYS[0].solve(input));
+}
@@ -0,0 +1,105 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, Ordering::Relaxed};
+
+/// What a piece of code allocated. `peak` is the most bytes it had live at
+/// once, on top of whatever was live when it started.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    pub count: u64,
+    pub bytes: u64,
+    pub peak: u64,
+}
+
+/// The system allocator plus process-wide counters. It is only installed with
+/// the `count-allocs` feature, since every allocation pays for the bookkeeping.
+pub struct CountingAlloc;
+
+static COUNT: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+static LIVE: AtomicU64 = AtomicU64::new(0);
+static PEAK: AtomicU64 = AtomicU64::new(0);
+
+#[cfg(any(test, feature = "count-allocs"))]
+#[global_allocator]
+static ALLOCATOR: CountingAlloc = CountingAlloc;
+
+fn record_alloc(size: usize) {
+    COUNT.fetch_add(1, Relaxed);
+    BYTES.fetch_add(size as u64, Relaxed);
+    let live = LIVE.fetch_add(size as u64, Relaxed) + size as u64;
+    PEAK.fetch_max(live, Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        LIVE.fetch_sub(layout.size() as u64, Relaxed);
+    }
+
+    /// Counted as a fresh allocation of `new_size`, since growing a `Vec` is
+    /// exactly the kind of thing we want to see.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new.is_null() {
+            LIVE.fetch_sub(layout.size() as u64, Relaxed);
+            record_alloc(new_size);
+        }
+        new
+    }
+}
+
+/// Runs `f` and reports what it allocated, or `None` if `CountingAlloc` isn't
+/// installed. The counters are shared by all threads, so anything else
+/// running at the same time gets counted too.
+pub fn count_allocs<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    if !cfg!(any(test, feature = "count-allocs")) {
+        return (f(), None);
+    }
+    let (count, bytes, live) = (COUNT.load(Relaxed), BYTES.load(Relaxed), LIVE.load(Relaxed));
+    PEAK.store(live, Relaxed);
+    let out = f();
+    let stats = AllocStats {
+        count: COUNT.load(Relaxed) - count,
+        bytes: BYTES.load(Relaxed) - bytes,
+        peak: PEAK.load(Relaxed).saturating_sub(live),
+    };
+    (out, Some(stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_allocs() {
+        let (v, stats) = count_allocs(|| {
+            let mut v = Vec::with_capacity(100);
+            v.extend(0..100u64);
+            drop(std::hint::black_box(vec![0u8; 4000]));
+            v
+        });
+        let stats = stats.unwrap();
+        assert_eq!(100, v.len());
+        // Other tests allocate on their own threads meanwhile, so these are
+        // lower bounds.
+        assert!(stats.count >= 2);
+        assert!(stats.bytes >= 4800);
+        assert!(stats.peak >= 4800);
+    }
+}
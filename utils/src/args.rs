@@ -0,0 +1,199 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+
+use crate::BenchOptions;
+
+pub const USAGE: &str = "usage: aoc_<year> <day|all> [options]
+       aoc_<year> check [--record]
+       aoc_<year> time [--parallel]
+       aoc_<year> new <day> [--wait]
+options:
+  --input <path>         read the input from a file instead of the puzzle input
+  --stdin                read the input from standard input
+  --example <N>          run against the Nth extracted example
+  --profile <name>       run against input/<name>/dayN.txt
+  --strict               fail instead of fixing CRLF or missing or extra newlines
+  --examples             extract the examples from the puzzle page first
+  --wait                 if the puzzle hasn't unlocked yet, wait for it
+  --part <1|2>           only solve one part
+  --quiet                print just the answers, one per line
+  --bench                benchmark each phase on the puzzle input and compare
+                         to the baseline
+  --threshold <percent>  slowdown that counts as a regression (default 10)
+  --save-baseline        store the benchmark results as the new baseline
+  --submit <1|2>         submit that part's answer
+The puzzle input is the current session's download, cached per account. Use
+--input or --profile to run against anyone else's.";
+
+/// Where a day's input comes from.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The current session's input from the per-account cache, downloaded if
+    /// it's missing.
+    #[default]
+    Puzzle,
+    File(PathBuf),
+    Stdin,
+    /// The `k`th (1-based) example written by `extract_examples`.
+    Example(usize),
+    /// `input/<profile>/dayN.txt`, someone else's input.
+    Profile(String),
+}
+
+/// Options for running one or all days, as parsed from the command line.
+#[derive(Clone, Debug, Default)]
+pub struct RunArgs {
+    /// A day number or `all`.
+    pub selection: String,
+    pub input: InputSource,
+    /// Refuse inputs that needed normalizing rather than fixing them quietly.
+    pub strict: bool,
+    pub part: Option<u8>,
+    pub quiet: bool,
+    pub examples: bool,
+    pub wait: bool,
+    pub bench: Option<BenchOptions>,
+    pub save_baseline: bool,
+    pub submit: Option<u8>,
+}
+
+impl RunArgs {
+    pub fn parse(selection: String, args: impl IntoIterator<Item = String>) -> Result<RunArgs> {
+        let mut run = RunArgs {
+            selection,
+            ..RunArgs::default()
+        };
+        let mut threshold = None;
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().unwrap_or_default();
+            match arg.as_str() {
+                "--input" => match value() {
+                    path if !path.is_empty() => run.set_input(InputSource::File(path.into()))?,
+                    _ => bail!("--input takes a path\n{USAGE}"),
+                },
+                "--stdin" => run.set_input(InputSource::Stdin)?,
+                "--example" => match value().parse() {
+                    Ok(k) if k > 0 => run.set_input(InputSource::Example(k))?,
+                    _ => bail!("--example takes a number, starting at 1\n{USAGE}"),
+                },
+                "--profile" => match value() {
+                    profile if !profile.is_empty() => run.set_input(InputSource::Profile(profile))?,
+                    _ => bail!("--profile takes a name\n{USAGE}"),
+                },
+                "--strict" => run.strict = true,
+                "--examples" => run.examples = true,
+                "--wait" => run.wait = true,
+                "--part" => run.part = Some(parse_part("--part", &value())?),
+                "--quiet" => run.quiet = true,
+                "--bench" => run.bench = Some(BenchOptions::default()),
+                "--threshold" => match value().parse() {
+                    Ok(t) => threshold = Some(t),
+                    _ => bail!("--threshold takes a percentage\n{USAGE}"),
+                },
+                "--save-baseline" => run.save_baseline = true,
+                "--submit" => run.submit = Some(parse_part("--submit", &value())?),
+                other => bail!("unexpected argument {other:?}\n{USAGE}"),
+            }
+        }
+
+        if let Some(threshold) = threshold {
+            match &mut run.bench {
+                Some(options) => options.threshold = threshold,
+                None => bail!("--threshold only makes sense with --bench"),
+            }
+        }
+        if run.save_baseline && run.bench.is_none() {
+            bail!("--save-baseline only makes sense with --bench");
+        }
+        // The baseline only knows day and phase, so timings of any other input
+        // would be compared against (or overwrite) the puzzle input's.
+        if run.bench.is_some() && run.input != InputSource::Puzzle {
+            bail!("--bench only works with the puzzle input");
+        }
+        if run.selection == "all" && matches!(run.input, InputSource::File(_) | InputSource::Stdin) {
+            bail!("a custom input needs a single day");
+        }
+        if run.submit.is_some() {
+            if run.selection == "all" {
+                bail!("--submit needs a single day");
+            }
+            if run.input != InputSource::Puzzle {
+                bail!("--submit only works with the puzzle input");
+            }
+            if run.part.is_some_and(|p| Some(p) != run.submit) {
+                bail!("--part and --submit ask for different parts");
+            }
+        }
+        Ok(run)
+    }
+
+    fn set_input(&mut self, input: InputSource) -> Result<()> {
+        if self.input != InputSource::Puzzle {
+            bail!("only one of --input, --stdin, --example and --profile can be given");
+        }
+        self.input = input;
+        Ok(())
+    }
+}
+
+fn parse_part(flag: &str, value: &str) -> Result<u8> {
+    match value {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => bail!("{flag} takes a part, 1 or 2\n{USAGE}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<RunArgs> {
+        let mut args = args.split_whitespace().map(str::to_string);
+        RunArgs::parse(args.next().unwrap(), args)
+    }
+
+    #[test]
+    fn test_parse() {
+        let run = parse("3 --example 2 --part 1 --quiet").unwrap();
+        assert!(!run.wait && !run.strict);
+        assert_eq!("3", run.selection);
+        assert_eq!(InputSource::Example(2), run.input);
+        assert_eq!(Some(1), run.part);
+        assert!(run.quiet && run.bench.is_none());
+
+        let run = parse("all --bench --threshold 25").unwrap();
+        assert_eq!(25.0, run.bench.unwrap().threshold);
+        assert!(parse("25 --wait --examples").unwrap().wait);
+        assert!(parse("all --strict").unwrap().strict);
+        assert_eq!(InputSource::File("x.txt".into()), parse("1 --input x.txt").unwrap().input);
+        assert_eq!(InputSource::Stdin, parse("1 --stdin").unwrap().input);
+        assert_eq!(InputSource::Profile("bob".into()), parse("all --profile bob").unwrap().input);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for args in [
+            "1 --part 3",
+            "1 --example 0",
+            "1 --input",
+            "1 --stdin --example 1",
+            "1 --profile bob --example 1",
+            "1 --profile",
+            "all --stdin",
+            "1 --threshold 5",
+            "1 --stdin --submit 1",
+            "1 --part 2 --submit 1",
+            "1 --example 1 --bench",
+            "1 --input x.txt --bench",
+            "1 --stdin --bench",
+            "all --profile bob --bench --save-baseline",
+            "all --submit 2",
+            "1 --verbose",
+        ] {
+            assert!(parse(args).is_err(), "{args}");
+        }
+    }
+}
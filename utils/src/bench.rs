@@ -0,0 +1,363 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use microbench::statistics::Model;
+use microbench::Options;
+
+use crate::{count_allocs, AllocStats, InputError, Solution};
+
+/// The parts of a day that get timed separately. `Combined` is a full
+/// parse + part1 + part2 run, which is what a user actually waits for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+    Combined,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 4] = [Phase::Parse, Phase::Part1, Phase::Part2, Phase::Combined];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+            Phase::Combined => "combined",
+        }
+    }
+
+    pub fn from_name(s: &str) -> Option<Phase> {
+        Phase::ALL.into_iter().find(|p| p.as_str() == s)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct BenchOptions {
+    /// How long to keep sampling each phase.
+    pub time: Duration,
+    /// Slowdown, in percent of the baseline, above which a phase is flagged.
+    pub threshold: f64,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions {
+            time: Duration::from_secs(1),
+            threshold: 10.0,
+        }
+    }
+}
+
+/// The fitted time per iteration of one phase of one day. `r2` is how well the
+/// samples fit a straight line; a low value means the timing was noisy.
+/// `allocs` is from a single run, and only there with `count-allocs`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Measurement {
+    pub day: u32,
+    pub phase: Phase,
+    pub ns_per_iter: f64,
+    pub r2: f64,
+    pub allocs: Option<AllocStats>,
+}
+
+/// Receives each phase of a day along with a closure that runs it once.
+pub type PhaseVisitor<'a> = dyn FnMut(Phase, &mut dyn FnMut()) + 'a;
+
+/// Hands each phase of `S` on `input` to `visit` as a closure that runs it
+/// once, so any harness can time them. Part 1 and part 2 reuse one parse.
+pub fn visit_phases<S: Solution>(input: &str, visit: &mut PhaseVisitor) {
+    let data = S::parse(input);
+    visit(Phase::Parse, &mut || drop(std::hint::black_box(S::parse(input))));
+    visit(Phase::Part1, &mut || drop(std::hint::black_box(S::part1(&data).to_string())));
+    visit(Phase::Part2, &mut || drop(std::hint::black_box(S::part2(&data).to_string())));
+    visit(Phase::Combined, &mut || {
+        let data = S::parse(input);
+        std::hint::black_box((S::part1(&data).to_string(), S::part2(&data).to_string()));
+    });
+}
+
+pub fn measure_solution<S: Solution>(day: u32, input: &str, options: &BenchOptions) -> Vec<Measurement> {
+    let options = Options::default().time(options.time);
+    let mut measurements = vec![];
+    visit_phases::<S>(input, &mut |phase, f| {
+        let (_, allocs) = count_allocs(&mut *f);
+        let model: Model = microbench::measure(&options, f)
+            .iter()
+            .map(|s| (s.iterations as f64, s.elapsed.0 as f64))
+            .collect();
+        measurements.push(Measurement {
+            day,
+            phase,
+            ns_per_iter: model.beta,
+            r2: model.r2,
+            allocs,
+        });
+    });
+    measurements
+}
+
+/// The allocation columns are left empty when nothing was counted.
+pub fn to_csv(measurements: &[Measurement]) -> String {
+    let mut csv = "day,phase,ns_per_iter,r2,allocs,bytes,peak\n".to_string();
+    for m in measurements {
+        let allocs = m.allocs.map_or(",,".to_string(), |a| format!("{},{},{}", a.count, a.bytes, a.peak));
+        csv += &format!("{},{},{:.1},{:.4},{}\n", m.day, m.phase.as_str(), m.ns_per_iter, m.r2, allocs);
+    }
+    csv
+}
+
+/// Parses what `to_csv` wrote, skipping the header and any line it can't read.
+/// Files from before allocations were counted have no allocation columns.
+pub fn from_csv(csv: &str) -> Vec<Measurement> {
+    csv.lines()
+        .filter_map(|line| {
+            let mut fields = line.split(',');
+            let day = fields.next()?.parse().ok()?;
+            let phase = Phase::from_name(fields.next()?)?;
+            let ns_per_iter = fields.next()?.parse().ok()?;
+            let r2 = fields.next()?.parse().ok()?;
+            let mut next = || fields.next()?.parse().ok();
+            let allocs = (|| {
+                Some(AllocStats {
+                    count: next()?,
+                    bytes: next()?,
+                    peak: next()?,
+                })
+            })();
+            Some(Measurement {
+                day,
+                phase,
+                ns_per_iter,
+                r2,
+                allocs,
+            })
+        })
+        .collect()
+}
+
+pub fn to_json(measurements: &[Measurement]) -> String {
+    let rows: Vec<_> = measurements
+        .iter()
+        .map(|m| {
+            let allocs = m.allocs.map_or("null".to_string(), |a| {
+                format!("{{\"count\": {}, \"bytes\": {}, \"peak\": {}}}", a.count, a.bytes, a.peak)
+            });
+            format!(
+                "  {{\"day\": {}, \"phase\": \"{}\", \"ns_per_iter\": {:.1}, \"r2\": {:.4}, \"allocs\": {}}}",
+                m.day,
+                m.phase.as_str(),
+                m.ns_per_iter,
+                m.r2,
+                allocs
+            )
+        })
+        .collect();
+    format!("[\n{}\n]\n", rows.join(",\n"))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Comparison {
+    pub current: Measurement,
+    pub baseline: Option<f64>,
+    pub regressed: bool,
+}
+
+impl Comparison {
+    /// Change relative to the baseline, in percent (positive is slower).
+    pub fn change(&self) -> Option<f64> {
+        self.baseline.map(|b| (self.current.ns_per_iter / b - 1.0) * 100.0)
+    }
+}
+
+pub fn compare(baseline: &[Measurement], current: &[Measurement], threshold: f64) -> Vec<Comparison> {
+    current
+        .iter()
+        .map(|&current| {
+            let baseline = baseline
+                .iter()
+                .find(|b| b.day == current.day && b.phase == current.phase)
+                .map(|b| b.ns_per_iter);
+            let mut comparison = Comparison {
+                current,
+                baseline,
+                regressed: false,
+            };
+            comparison.regressed = comparison.change().is_some_and(|c| c > threshold);
+            comparison
+        })
+        .collect()
+}
+
+/// `baseline` with every day that was measured again replaced by its new numbers.
+pub fn merge(baseline: &[Measurement], current: &[Measurement]) -> Vec<Measurement> {
+    let mut merged: Vec<_> = baseline
+        .iter()
+        .filter(|b| !current.iter().any(|c| c.day == b.day))
+        .chain(current)
+        .copied()
+        .collect();
+    merged.sort_by_key(|m| (m.day, m.phase));
+    merged
+}
+
+/// The allocation columns only appear if some measurement has them.
+pub fn format_report(comparisons: &[Comparison]) -> String {
+    let with_allocs = comparisons.iter().any(|c| c.current.allocs.is_some());
+    let mut report = format!(
+        "{:>3}  {:<8}  {:>14}  {:>6}  {:>14}  {:>8}",
+        "day", "phase", "time", "r2", "baseline", "change"
+    );
+    if with_allocs {
+        report += &format!("  {:>8}  {:>11}  {:>11}", "allocs", "bytes", "peak");
+    }
+    report += "\n";
+    for c in comparisons {
+        let baseline = c.baseline.map_or("-".to_string(), format_ns);
+        let change = c.change().map_or("-".to_string(), |c| format!("{c:+.1}%"));
+        report += &format!(
+            "{:>3}  {:<8}  {:>14}  {:>6.3}  {:>14}  {:>8}",
+            c.current.day,
+            c.current.phase.as_str(),
+            format_ns(c.current.ns_per_iter),
+            c.current.r2,
+            baseline,
+            change,
+        );
+        if with_allocs {
+            report += &match c.current.allocs {
+                Some(a) => format!("  {:>8}  {:>11}  {:>11}", a.count, format_bytes(a.bytes), format_bytes(a.peak)),
+                None => format!("  {:>8}  {:>11}  {:>11}", "-", "-", "-"),
+            };
+        }
+        report += if c.regressed { "  REGRESSED\n" } else { "\n" };
+    }
+    report
+}
+
+pub fn format_ns(ns: f64) -> String {
+    match ns {
+        ns if ns >= 1e9 => format!("{:.3} s", ns / 1e9),
+        ns if ns >= 1e6 => format!("{:.3} ms", ns / 1e6),
+        ns if ns >= 1e3 => format!("{:.3} µs", ns / 1e3),
+        ns => format!("{ns:.1} ns"),
+    }
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    match bytes as f64 {
+        b if b >= (1 << 30) as f64 => format!("{:.1} GiB", b / (1 << 30) as f64),
+        b if b >= (1 << 20) as f64 => format!("{:.1} MiB", b / (1 << 20) as f64),
+        b if b >= (1 << 10) as f64 => format!("{:.1} KiB", b / (1 << 10) as f64),
+        _ => format!("{bytes} B"),
+    }
+}
+
+pub fn bench_dir(root_dir: &str) -> PathBuf {
+    Path::new(root_dir).join("bench")
+}
+
+pub fn load_baseline(root_dir: &str) -> Result<Vec<Measurement>, InputError> {
+    let path = bench_dir(root_dir).join("baseline.csv");
+    match std::fs::read_to_string(&path) {
+        Ok(csv) => Ok(from_csv(&csv)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(vec![]),
+        Err(source) => Err(InputError::Io { path, source }),
+    }
+}
+
+/// Writes `bench/latest.csv` and `bench/latest.json`, and with `save_baseline`
+/// also folds the results into `bench/baseline.csv`.
+pub fn save_results(root_dir: &str, measurements: &[Measurement], save_baseline: bool) -> Result<(), InputError> {
+    let dir = bench_dir(root_dir);
+    let write = |name: &str, contents: String| {
+        let path = dir.join(name);
+        std::fs::write(&path, contents).map_err(|source| InputError::Io { path, source })
+    };
+    std::fs::create_dir_all(&dir).map_err(|source| InputError::Io {
+        path: dir.clone(),
+        source,
+    })?;
+    write("latest.csv", to_csv(measurements))?;
+    write("latest.json", to_json(measurements))?;
+    if save_baseline {
+        let baseline = load_baseline(root_dir)?;
+        write("baseline.csv", to_csv(&merge(&baseline, measurements)))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::temp_dir;
+
+    fn m(day: u32, phase: Phase, ns_per_iter: f64) -> Measurement {
+        Measurement {
+            day,
+            phase,
+            ns_per_iter,
+            r2: 1.0,
+            allocs: None,
+        }
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let mut measurements = vec![m(1, Phase::Parse, 1500.0), m(1, Phase::Combined, 2.5e6)];
+        measurements[1].allocs = Some(AllocStats {
+            count: 3,
+            bytes: 2048,
+            peak: 1024,
+        });
+        assert_eq!(measurements, from_csv(&to_csv(&measurements)));
+        assert_eq!(vec![m(2, Phase::Part1, 10.0)], from_csv("day,phase,ns_per_iter,r2\n2,part1,10.0,1.0\n"));
+        assert_eq!(
+            "[\n  {\"day\": 1, \"phase\": \"parse\", \"ns_per_iter\": 1500.0, \"r2\": 1.0000, \"allocs\": null}\n]\n",
+            to_json(&measurements[..1])
+        );
+        assert!(to_json(&measurements).contains("\"allocs\": {\"count\": 3, \"bytes\": 2048, \"peak\": 1024}"));
+    }
+
+    #[test]
+    fn test_format_report() {
+        let mut current = m(1, Phase::Part1, 1500.0);
+        let plain = format_report(&compare(&[], &[current], 10.0));
+        assert!(!plain.contains("allocs"));
+        current.allocs = Some(AllocStats {
+            count: 12,
+            bytes: 3 << 20,
+            peak: 1536,
+        });
+        let report = format_report(&compare(&[], &[current], 10.0));
+        assert!(report.contains("allocs"));
+        assert!(report.contains("      12      3.0 MiB      1.5 KiB\n"));
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = vec![m(1, Phase::Part1, 100.0), m(1, Phase::Part2, 100.0)];
+        let current = vec![m(1, Phase::Part1, 105.0), m(1, Phase::Part2, 120.0), m(2, Phase::Part1, 1.0)];
+        let flags: Vec<_> = compare(&baseline, &current, 10.0)
+            .iter()
+            .map(|c| (c.baseline, c.regressed))
+            .collect();
+        assert_eq!(vec![(Some(100.0), false), (Some(100.0), true), (None, false)], flags);
+    }
+
+    #[test]
+    fn test_save_baseline() {
+        let root = temp_dir("bench_baseline");
+        let root = root.to_str().unwrap();
+        save_results(root, &[m(1, Phase::Part1, 10.0), m(2, Phase::Part1, 20.0)], true).unwrap();
+        save_results(root, &[m(2, Phase::Part1, 30.0)], true).unwrap();
+        assert_eq!(
+            vec![m(1, Phase::Part1, 10.0), m(2, Phase::Part1, 30.0)],
+            load_baseline(root).unwrap()
+        );
+        save_results(root, &[m(1, Phase::Part1, 99.0)], false).unwrap();
+        assert_eq!(10.0, load_baseline(root).unwrap()[0].ns_per_iter);
+        assert!(bench_dir(root).join("latest.json").exists());
+    }
+}
@@ -0,0 +1,315 @@
+use reqwest::{blocking::Client, header::COOKIE};
+use std::fs::read_to_string;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::file_utils::session_cookie;
+use crate::examples::DAY_DESC;
+use crate::{account_of, ensure_unlocked, session_from_env, InputCache, InputError, Verdict};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const BASE_URL_ENV_VAR: &str = "AOC_BASE_URL";
+const USER_AGENT: &str = "github.com/quicknir/advent_rust by quicknir";
+/// Leave at least this long between two requests from this process.
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Talks to the Advent of Code site (or whatever `base_url` points at) on
+/// behalf of one session.
+pub struct AocClient {
+    client: Client,
+    base_url: String,
+    cookie: String,
+    throttle: Throttle,
+}
+
+impl AocClient {
+    pub fn new(session: &str) -> Result<AocClient, InputError> {
+        let client = Client::builder()
+            .user_agent(USER_AGENT)
+            .build()
+            .map_err(|source| InputError::Request {
+                url: DEFAULT_BASE_URL.to_string(),
+                source,
+            })?;
+        Ok(AocClient {
+            client,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            cookie: session_cookie(session),
+            throttle: Throttle::new(DEFAULT_MIN_INTERVAL),
+        })
+    }
+
+    /// Session from `AOC_SESSION`, the per-user config file or
+    /// `<input_dir>/cookie.txt`, in that order; base URL from `AOC_BASE_URL`.
+    pub fn from_env(input_dir: &Path) -> Result<AocClient, InputError> {
+        let client = AocClient::new(&session_from_env(input_dir)?)?;
+        Ok(match std::env::var(BASE_URL_ENV_VAR) {
+            Ok(base_url) => client.with_base_url(base_url),
+            Err(_) => client,
+        })
+    }
+
+    /// See `account_of`.
+    pub fn account(&self) -> String {
+        account_of(&self.cookie)
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> AocClient {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_min_interval(mut self, min_interval: Duration) -> AocClient {
+        self.throttle = Throttle::new(min_interval);
+        self
+    }
+
+    pub fn fetch_input(&self, year: i16, day: u32) -> Result<String, InputError> {
+        ensure_unlocked(year, day)?;
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let body = self.get(&url)?;
+        if !looks_like_input(&body) {
+            return Err(InputError::BadBody {
+                url,
+                body: body.trim().to_string(),
+            });
+        }
+        Ok(body)
+    }
+
+    pub fn fetch_puzzle_page(&self, year: i16, day: u32) -> Result<String, InputError> {
+        ensure_unlocked(year, day)?;
+        let url = format!("{}/{year}/day/{day}", self.base_url);
+        let body = self.get(&url)?;
+        if !body.contains(DAY_DESC) {
+            return Err(InputError::UnexpectedReply {
+                url,
+                body: body.trim().to_string(),
+            });
+        }
+        Ok(body)
+    }
+
+    fn get(&self, url: &str) -> Result<String, InputError> {
+        let request_error = |source| InputError::Request {
+            url: url.to_string(),
+            source,
+        };
+        self.throttle.wait();
+        let response = self
+            .client
+            .get(url)
+            .header(COOKIE, &self.cookie)
+            .send()
+            .map_err(request_error)?;
+        let status = response.status();
+        let body = response.text().map_err(request_error)?;
+        if !status.is_success() {
+            return Err(InputError::BadStatus {
+                url: url.to_string(),
+                status,
+                body: body.trim().to_string(),
+            });
+        }
+        Ok(body)
+    }
+
+    pub fn submit(&self, year: i16, day: u32, part: u8, answer: &str) -> Result<Verdict, InputError> {
+        ensure_unlocked(year, day)?;
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let request_error = |source| InputError::Request {
+            url: url.clone(),
+            source,
+        };
+        let level = part.to_string();
+        self.throttle.wait();
+        let response = self
+            .client
+            .post(&url)
+            .header(COOKIE, &self.cookie)
+            .form(&[("level", level.as_str()), ("answer", answer)])
+            .send()
+            .map_err(request_error)?;
+        let status = response.status();
+        let body = response.text().map_err(request_error)?;
+        if !status.is_success() {
+            return Err(InputError::BadStatus {
+                url,
+                status,
+                body: body.trim().to_string(),
+            });
+        }
+        Verdict::from_reply(&body).ok_or(InputError::UnexpectedReply { url, body })
+    }
+}
+
+impl InputCache {
+    pub fn get_or_download(&self, client: &AocClient, year: i16, day: u32) -> Result<String, InputError> {
+        let path = self.path(&client.account(), year, day);
+        if let Ok(input) = read_to_string(&path) {
+            return Ok(input);
+        }
+        let body = client.fetch_input(year, day)?;
+
+        // Write next to the target and rename, so an interrupted download never
+        // leaves a truncated file that later runs would happily read.
+        let io_error = |path: &Path| {
+            let path = path.to_path_buf();
+            move |source| InputError::Io { path, source }
+        };
+        let dir = path.parent().unwrap_or(Path::new("."));
+        std::fs::create_dir_all(dir).map_err(io_error(dir))?;
+        let partial = path.with_extension("txt.partial");
+        std::fs::write(&partial, &body).map_err(io_error(&partial))?;
+        std::fs::rename(&partial, &path).map_err(io_error(&path))?;
+        Ok(body)
+    }
+}
+
+/// The server answers some failures (not logged in, puzzle not unlocked yet,
+/// rate limiting) with a 200 or with a plain sentence instead of the input, so
+/// the status code alone isn't enough to decide whether to cache a body.
+fn looks_like_input(body: &str) -> bool {
+    const ERROR_MARKERS: [&str; 4] = [
+        "Please log in",
+        "Please don't repeat requests",
+        "before it unlocks",
+        "<!DOCTYPE",
+    ];
+    !body.trim().is_empty()
+        && body.ends_with('\n')
+        && !ERROR_MARKERS.iter().any(|m| body.contains(m))
+}
+
+/// When this process last sent a request, shared by every client: reading
+/// inputs, fetching examples and scaffolding each make their own.
+static LAST_REQUEST: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// Spaces requests at least `min_interval` after the process's previous one.
+#[derive(Debug)]
+struct Throttle {
+    min_interval: Duration,
+}
+
+impl Throttle {
+    fn new(min_interval: Duration) -> Throttle {
+        Throttle { min_interval }
+    }
+
+    /// Blocks until a request may be sent, and counts it as sent.
+    fn wait(&self) {
+        let mut last = LAST_REQUEST.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(last) = *last {
+            let next = last + self.min_interval;
+            let now = Instant::now();
+            if next > now {
+                std::thread::sleep(next - now);
+            }
+        }
+        *last = Some(Instant::now());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::{temp_dir, TestServer};
+    #[test]
+    fn test_looks_like_input() {
+        assert!(looks_like_input("1 2\n3 4\n"));
+        assert!(!looks_like_input(""));
+        assert!(!looks_like_input("\n"));
+        assert!(!looks_like_input("1 2\n3 4"));
+        assert!(!looks_like_input("Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"));
+        assert!(!looks_like_input("Please don't repeat requests for this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.\n"));
+        assert!(!looks_like_input("<!DOCTYPE html>\n<html></html>\n"));
+    }
+    #[test]
+    fn test_account() {
+        let account = |session| AocClient::new(session).unwrap().account();
+        assert_eq!(account_of("abc"), account("abc"));
+        let cache = InputCache::new("/cache");
+        assert_ne!(cache.path(&account("abc"), 2023, 1), cache.path(&account("abd"), 2023, 1));
+    }
+    #[test]
+    fn test_download() {
+        let server = TestServer::new(vec![(200, "1 2\n3 4\n")]);
+        let client = AocClient::new("session=abc\n").unwrap().with_base_url(&server.url);
+        let cache = InputCache::new(temp_dir("download"));
+        assert_eq!("1 2\n3 4\n", cache.get_or_download(&client, 2023, 7).unwrap());
+        let target = cache.path(&client.account(), 2023, 7);
+        assert!(target.ends_with(format!("{}/2023/day7.txt", client.account())));
+        assert_eq!("1 2\n3 4\n", std::fs::read_to_string(&target).unwrap());
+        // Served from the cache, the server only had one response.
+        assert_eq!("1 2\n3 4\n", cache.get_or_download(&client, 2023, 7).unwrap());
+
+        let request = server.requests().pop().unwrap();
+        assert!(request.starts_with("GET /2023/day/7/input "));
+        assert!(request.contains("cookie: session=abc\r\n"));
+        assert!(request.contains(&format!("user-agent: {USER_AGENT}\r\n")));
+    }
+    #[test]
+    fn test_download_errors_not_cached() {
+        let server = TestServer::new(vec![
+            (404, "404 Not Found"),
+            (200, "Please don't repeat requests for this endpoint before it unlocks!\n"),
+            (500, "oops"),
+        ]);
+        let client = AocClient::new("abc")
+            .unwrap()
+            .with_base_url(&server.url)
+            .with_min_interval(Duration::ZERO);
+        let cache = InputCache::new(temp_dir("download_errors"));
+        let err = cache.get_or_download(&client, 2024, 1);
+        assert!(matches!(err, Err(InputError::BadStatus { status, .. }) if status == 404));
+        let err = cache.get_or_download(&client, 2024, 1);
+        assert!(matches!(err, Err(InputError::BadBody { .. })));
+        let err = cache.get_or_download(&client, 2024, 1);
+        assert!(matches!(err, Err(InputError::BadStatus { status, .. }) if status == 500));
+        assert!(!cache.path(&client.account(), 2024, 1).exists());
+        let err = cache.get_or_download(&client, 2999, 1);
+        assert!(matches!(err, Err(InputError::NotUnlocked { day: 1, .. })));
+        assert!(server.requests()[0].contains("cookie: session=abc\r\n"));
+    }
+    #[test]
+    fn test_puzzle_page() {
+        let page = format!("<main>{DAY_DESC}<h2>--- Day 1 ---</h2></article></main>");
+        let server = TestServer::new(vec![(200, "<html>Please log in</html>"), (200, page.as_str())]);
+        let client = AocClient::new("abc")
+            .unwrap()
+            .with_base_url(&server.url)
+            .with_min_interval(Duration::ZERO);
+        let err = client.fetch_puzzle_page(2023, 1);
+        assert!(matches!(err, Err(InputError::UnexpectedReply { body, .. }) if body.contains("log in")));
+        assert_eq!(page, client.fetch_puzzle_page(2023, 1).unwrap());
+        assert!(server.requests()[0].starts_with("GET /2023/day/1 "));
+    }
+    #[test]
+    fn test_throttle() {
+        // Other tests send requests too, so the first wait may or may not have
+        // to sleep; the two after it always do.
+        let throttle = Throttle::new(Duration::from_millis(50));
+        let start = Instant::now();
+        throttle.wait();
+        throttle.wait();
+        throttle.wait();
+        assert!(start.elapsed() >= Duration::from_millis(100));
+    }
+    #[test]
+    fn test_throttle_across_clients() {
+        let page = format!("<main>{DAY_DESC}<p>one</p></article></main>");
+        let server = TestServer::new(vec![(200, page.as_str()), (200, page.as_str())]);
+        let client = || {
+            AocClient::new("abc")
+                .unwrap()
+                .with_base_url(&server.url)
+                .with_min_interval(Duration::from_millis(50))
+        };
+        let (first, second) = (client(), client());
+        let start = Instant::now();
+        first.fetch_puzzle_page(2023, 1).unwrap();
+        second.fetch_puzzle_page(2023, 2).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(50), "{:?}", start.elapsed());
+    }
+}
@@ -0,0 +1,177 @@
+use std::time::{Duration, Instant};
+
+use rayon::prelude::*;
+
+use crate::{cached_input, format_ns, Day, Input};
+
+/// How many of the slowest days get flagged in the table.
+const SLOWEST: usize = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Timing {
+    pub day: u32,
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timing {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Dashboard {
+    /// Slowest day first.
+    pub timings: Vec<Timing>,
+    /// Days that had no input to run against.
+    pub skipped: Vec<u32>,
+    /// How long the whole run took, which is less than the sum of the days
+    /// when they ran in parallel.
+    pub wall: Duration,
+}
+
+impl Dashboard {
+    pub fn total(&self) -> Duration {
+        self.timings.iter().map(Timing::total).sum()
+    }
+}
+
+/// Runs every day once against `input`, optionally spreading the days over
+/// rayon's thread pool.
+pub fn time_days(days: &[Day], input: impl Fn(u32) -> Option<String> + Sync, parallel: bool) -> Dashboard {
+    let start = Instant::now();
+    let run = |day: &Day| {
+        input(day.day).map(|input| {
+            let [parse, part1, part2] = day.time(&input);
+            Timing {
+                day: day.day,
+                parse,
+                part1,
+                part2,
+            }
+        })
+    };
+    let results: Vec<_> = if parallel {
+        days.par_iter().map(run).collect()
+    } else {
+        days.iter().map(run).collect()
+    };
+    let wall = start.elapsed();
+
+    let skipped = days
+        .iter()
+        .zip(&results)
+        .filter(|(_, r)| r.is_none())
+        .map(|(d, _)| d.day)
+        .collect();
+    let mut timings: Vec<_> = results.into_iter().flatten().collect();
+    timings.sort_by_key(|t| std::cmp::Reverse(t.total()));
+    Dashboard {
+        timings,
+        skipped,
+        wall,
+    }
+}
+
+/// `time_days` against the year's cached inputs.
+pub fn time_year(root_dir: &str, year: i16, days: &[Day], parallel: bool) -> Dashboard {
+    time_days(days, |day| cached_input(root_dir, year, day).map(Input::into_string), parallel)
+}
+
+pub fn format_dashboard(dashboard: &Dashboard) -> String {
+    let ns = |d: Duration| format_ns(d.as_nanos() as f64);
+    let mut table = format!(
+        "{:>5}  {:>12}  {:>12}  {:>12}  {:>12}\n",
+        "day", "parse", "part1", "part2", "total"
+    );
+    for (i, t) in dashboard.timings.iter().enumerate() {
+        table += &format!(
+            "{:>5}  {:>12}  {:>12}  {:>12}  {:>12}{}\n",
+            t.day,
+            ns(t.parse),
+            ns(t.part1),
+            ns(t.part2),
+            ns(t.total()),
+            if i < SLOWEST { "  <- slow" } else { "" }
+        );
+    }
+    let sum = |f: fn(&Timing) -> Duration| ns(dashboard.timings.iter().map(f).sum());
+    table += &format!(
+        "{:>5}  {:>12}  {:>12}  {:>12}  {:>12}\n",
+        "total",
+        sum(|t| t.parse),
+        sum(|t| t.part1),
+        sum(|t| t.part2),
+        ns(dashboard.total())
+    );
+    table += &format!("wall clock: {}\n", ns(dashboard.wall));
+    if !dashboard.skipped.is_empty() {
+        let skipped: Vec<_> = dashboard.skipped.iter().map(u32::to_string).collect();
+        table += &format!("no input for days {}\n", skipped.join(", "));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Solution;
+
+    /// Sleeps for as many milliseconds as the input says in part 2.
+    struct Sleep;
+
+    impl Solution for Sleep {
+        type Parsed<'a> = u64;
+
+        fn parse(input: &str) -> Self::Parsed<'_> {
+            input.parse().unwrap()
+        }
+        fn part1(_: &Self::Parsed<'_>) -> impl std::fmt::Display {
+            0
+        }
+        fn part2(data: &Self::Parsed<'_>) -> impl std::fmt::Display {
+            std::thread::sleep(Duration::from_millis(*data));
+            0
+        }
+    }
+
+    const DAYS: &[Day] = &[Day::new::<Sleep>(1), Day::new::<Sleep>(2), Day::new::<Sleep>(3)];
+
+    fn input(day: u32) -> Option<String> {
+        match day {
+            1 => Some("5".into()),
+            3 => Some("30".into()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_time_days() {
+        for parallel in [false, true] {
+            let dashboard = time_days(DAYS, input, parallel);
+            assert_eq!(vec![3, 1], dashboard.timings.iter().map(|t| t.day).collect::<Vec<_>>());
+            assert_eq!(vec![2], dashboard.skipped);
+            assert!(dashboard.timings[0].part2 >= Duration::from_millis(30));
+            assert!(dashboard.total() >= Duration::from_millis(35));
+        }
+    }
+
+    #[test]
+    fn test_format_dashboard() {
+        let dashboard = Dashboard {
+            timings: vec![Timing {
+                day: 17,
+                parse: Duration::from_micros(2),
+                part1: Duration::from_millis(3),
+                part2: Duration::from_secs(1),
+            }],
+            skipped: vec![4, 5],
+            wall: Duration::from_secs(1),
+        };
+        let table = format_dashboard(&dashboard);
+        assert!(table.contains("   17      2.000 µs      3.000 ms       1.000 s       1.003 s  <- slow\n"));
+        assert!(table.contains("no input for days 4, 5\n"));
+    }
+}
@@ -0,0 +1,231 @@
+use std::fmt::{self, Debug, Display};
+
+use crate::rng::{seed_from_env, Rng, SEED_ENV_VAR};
+
+/// Seed used unless `SEED_ENV_VAR` says otherwise, so runs are repeatable.
+pub const DEFAULT_SEED: u64 = 2023;
+pub const DEFAULT_CASES: usize = 256;
+/// Upper bound on shrinking steps, in case a shrinker goes in circles.
+const MAX_SHRINKS: usize = 1000;
+
+type Shrinker<'a, I> = Box<dyn Fn(&I) -> Vec<I> + 'a>;
+type Variant<'a, I, O> = (&'static str, Box<dyn Fn(&I) -> O + 'a>);
+/// Each variant's name and output.
+type Outputs<O> = Vec<(&'static str, O)>;
+
+/// Runs alternative implementations of the same thing against generated
+/// inputs and checks that they all give the same answer.
+pub struct Differential<'a, I, O> {
+    generate: Box<dyn Fn(&mut Rng) -> I + 'a>,
+    shrink: Shrinker<'a, I>,
+    variants: Vec<Variant<'a, I, O>>,
+    cases: usize,
+    seed: u64,
+}
+
+/// A generated input the variants disagreed on, shrunk as far as it would go
+/// while they still disagreed.
+#[derive(Clone, Debug)]
+pub struct Disagreement<I, O> {
+    pub seed: u64,
+    /// Which generated case (0-based) first disagreed.
+    pub case: usize,
+    pub original: I,
+    pub input: I,
+    pub shrinks: usize,
+    /// Every variant's output on `input`, in registration order.
+    pub outputs: Outputs<O>,
+}
+
+impl<'a, I: Clone + Debug, O: PartialEq + Debug> Differential<'a, I, O> {
+    pub fn new(generate: impl Fn(&mut Rng) -> I + 'a) -> Self {
+        Differential {
+            generate: Box::new(generate),
+            shrink: Box::new(|_| vec![]),
+            variants: vec![],
+            cases: DEFAULT_CASES,
+            seed: seed_from_env(DEFAULT_SEED),
+        }
+    }
+
+    pub fn variant(mut self, name: &'static str, f: impl Fn(&I) -> O + 'a) -> Self {
+        self.variants.push((name, Box::new(f)));
+        self
+    }
+
+    /// Smaller versions of an input to try once the variants disagree, most
+    /// promising first. Without one, the generated input is reported as is.
+    pub fn with_shrink(mut self, shrink: impl Fn(&I) -> Vec<I> + 'a) -> Self {
+        self.shrink = Box::new(shrink);
+        self
+    }
+
+    pub fn with_cases(mut self, cases: usize) -> Self {
+        self.cases = cases;
+        self
+    }
+
+    /// Takes precedence over `SEED_ENV_VAR`.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn run(&self) -> Result<(), Disagreement<I, O>> {
+        assert!(self.variants.len() >= 2, "need at least two variants to compare");
+        let mut rng = Rng::new(self.seed);
+        for case in 0..self.cases {
+            let original = (self.generate)(&mut rng);
+            let outputs = self.outputs(&original);
+            if agree(&outputs) {
+                continue;
+            }
+            let (input, outputs, shrinks) = self.shrink_disagreement(original.clone(), outputs);
+            return Err(Disagreement {
+                seed: self.seed,
+                case,
+                original,
+                input,
+                shrinks,
+                outputs,
+            });
+        }
+        Ok(())
+    }
+
+    /// `run`, panicking with the disagreement, for use in tests.
+    pub fn assert_agree(&self) {
+        if let Err(disagreement) = self.run() {
+            panic!("{disagreement}");
+        }
+    }
+
+    fn outputs(&self, input: &I) -> Outputs<O> {
+        self.variants.iter().map(|(name, f)| (*name, f(input))).collect()
+    }
+
+    /// Greedily takes the first smaller input that still disagrees until none do.
+    fn shrink_disagreement(&self, mut input: I, mut outputs: Outputs<O>) -> (I, Outputs<O>, usize) {
+        let mut shrinks = 0;
+        'shrink: while shrinks < MAX_SHRINKS {
+            for candidate in (self.shrink)(&input) {
+                let candidate_outputs = self.outputs(&candidate);
+                if !agree(&candidate_outputs) {
+                    input = candidate;
+                    outputs = candidate_outputs;
+                    shrinks += 1;
+                    continue 'shrink;
+                }
+            }
+            break;
+        }
+        (input, outputs, shrinks)
+    }
+}
+
+fn agree<O: PartialEq>(outputs: &Outputs<O>) -> bool {
+    outputs.windows(2).all(|w| w[0].1 == w[1].1)
+}
+
+impl<I: Debug, O: Debug> Display for Disagreement<I, O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "variants disagree on case {} (rerun with {SEED_ENV_VAR}={})",
+            self.case, self.seed
+        )?;
+        writeln!(f, "  input: {:?}", self.input)?;
+        if self.shrinks > 0 {
+            writeln!(f, "  shrunk in {} steps from: {:?}", self.shrinks, self.original)?;
+        }
+        for (name, output) in &self.outputs {
+            writeln!(f, "  {name}: {output:?}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Candidates for shrinking a list: both halves, then each element removed,
+/// then each element replaced by its own shrinks.
+pub fn shrink_vec<T: Clone>(v: &[T], shrink_item: impl Fn(&T) -> Vec<T>) -> Vec<Vec<T>> {
+    let mut candidates = vec![];
+    if v.len() > 1 {
+        candidates.push(v[..v.len() / 2].to_vec());
+        candidates.push(v[v.len() / 2..].to_vec());
+    }
+    for i in 0..v.len() {
+        let mut smaller = v.to_vec();
+        smaller.remove(i);
+        candidates.push(smaller);
+    }
+    for (i, item) in v.iter().enumerate() {
+        for replacement in shrink_item(item) {
+            let mut simpler = v.to_vec();
+            simpler[i] = replacement;
+            candidates.push(simpler);
+        }
+    }
+    candidates
+}
+
+/// Candidates for shrinking a number, all closer to zero: zero itself, half,
+/// and one step towards it.
+pub fn shrink_int(n: i64) -> Vec<i64> {
+    let mut candidates = vec![0, n / 2, n - n.signum()];
+    candidates.dedup();
+    candidates.retain(|&c| c != n);
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sum_to(n: &i64) -> i64 {
+        (1..=*n).sum()
+    }
+
+    #[test]
+    fn test_agree() {
+        Differential::new(|rng| rng.range(0..=1000))
+            .variant("loop", sum_to)
+            .variant("formula", |&n| n * (n + 1) / 2)
+            .assert_agree();
+    }
+
+    #[test]
+    fn test_shrink_disagreement() {
+        // The "fast" version is wrong from 100 on.
+        let disagreement = Differential::new(|rng| rng.range(0..=1000))
+            .variant("loop", sum_to)
+            .variant("buggy", |&n| if n >= 100 { 0 } else { n * (n + 1) / 2 })
+            .with_shrink(|&n| shrink_int(n))
+            .with_seed(1)
+            .run()
+            .unwrap_err();
+        assert_eq!(100, disagreement.input);
+        assert!(disagreement.original >= 100);
+        assert_eq!(vec![("loop", 5050), ("buggy", 0)], disagreement.outputs);
+        assert!(disagreement.to_string().contains("AOC_SEED=1"));
+
+        let list = |rng: &mut Rng| (0..rng.range(0..=20)).map(|_| rng.range(-9..=9)).collect::<Vec<_>>();
+        let disagreement = Differential::new(list)
+            .variant("sum", |v| v.iter().sum::<i64>())
+            .variant("positive sum", |v| v.iter().filter(|&&x| x > 0).sum())
+            .with_shrink(|v| shrink_vec(v, |&x| shrink_int(x)))
+            .run()
+            .unwrap_err();
+        assert_eq!(vec![-1], disagreement.input);
+    }
+
+    #[test]
+    fn test_shrink_helpers() {
+        assert_eq!(vec![0, 5, 9], shrink_int(10));
+        assert_eq!(vec![0], shrink_int(-1));
+        assert!(shrink_int(0).is_empty());
+        assert_eq!(
+            vec![vec![1], vec![2], vec![2], vec![1], vec![0, 2], vec![1, 0], vec![1, 1]],
+            shrink_vec(&[1, 2], |&x| shrink_int(x))
+        );
+    }
+}
@@ -0,0 +1,283 @@
+use std::path::{Path, PathBuf};
+
+use crate::{account_of, session_from_env, Input, InputCache, InputError};
+
+/// The example inputs of a puzzle page, and the answers the text gives for them.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Examples {
+    pub blocks: Vec<String>,
+    pub answers: Vec<ExampleAnswer>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExampleAnswer {
+    pub part: u8,
+    /// 1-based index into `blocks` of the example this answer most likely belongs
+    /// to: the last one shown before it on the page.
+    pub example: usize,
+    pub answer: String,
+}
+
+impl Examples {
+    /// Pulls every `<pre><code>` block out of the page, and takes the last
+    /// `<code><em>` of each part's description as that part's expected answer.
+    pub fn from_page(html: &str) -> Examples {
+        const BLOCK: &str = "<pre><code>";
+        const ANSWER: &str = "<code><em>";
+        let mut examples = Examples::default();
+        let articles = html.split("<article").skip(1);
+        for (part, article) in (1..).zip(articles) {
+            let article = article.split("</article>").next().unwrap_or("");
+            let mut answer = None;
+            let mut rest = article;
+            loop {
+                let next_block = rest.find(BLOCK);
+                let next_answer = rest.find(ANSWER);
+                let (start, is_block) = match (next_block, next_answer) {
+                    (Some(b), Some(a)) if b < a => (b + BLOCK.len(), true),
+                    (_, Some(a)) => (a + ANSWER.len(), false),
+                    (Some(b), None) => (b + BLOCK.len(), true),
+                    (None, None) => break,
+                };
+                rest = &rest[start..];
+                let close = if is_block { "</code></pre>" } else { "</em></code>" };
+                let end = rest.find(close).unwrap_or(rest.len());
+                let text = html_text(&rest[..end]);
+                rest = &rest[end..];
+                if is_block {
+                    examples.blocks.push(text);
+                } else {
+                    answer = Some(text);
+                }
+            }
+            if let Some(answer) = answer {
+                examples.answers.push(ExampleAnswer {
+                    part,
+                    example: examples.blocks.len(),
+                    answer,
+                });
+            }
+        }
+        examples
+    }
+
+    /// Writes `day<N>_<k>.txt` for every block, and `day<N>_answers.tsv` with one
+    /// `part<TAB>example<TAB>answer` line per expected answer.
+    pub fn write(&self, dir: &Path, day: u32) -> Result<Vec<PathBuf>, InputError> {
+        let io_error = |path: &Path| {
+            let path = path.to_path_buf();
+            move |source| InputError::Io { path, source }
+        };
+        std::fs::create_dir_all(dir).map_err(io_error(dir))?;
+        let mut written = vec![];
+        for (k, block) in (1..).zip(&self.blocks) {
+            let path = dir.join(format!("day{day}_{k}.txt"));
+            std::fs::write(&path, block).map_err(io_error(&path))?;
+            written.push(path);
+        }
+        let answers: String = self
+            .answers
+            .iter()
+            .map(|a| format!("{}\t{}\t{}\n", a.part, a.example, a.answer))
+            .collect();
+        let path = dir.join(format!("day{day}_answers.tsv"));
+        std::fs::write(&path, answers).map_err(io_error(&path))?;
+        written.push(path);
+        Ok(written)
+    }
+}
+
+pub fn examples_dir(root_dir: &str) -> PathBuf {
+    Path::new(root_dir).join("input").join("examples")
+}
+
+/// Opens every part's description on a puzzle page.
+pub(crate) const DAY_DESC: &str = "<article class=\"day-desc\">";
+
+/// Reads the puzzle page from the current session's cache, downloading it
+/// first if it's missing or doesn't show part 2 yet (that only appears once
+/// part 1 is solved), and writes its examples under `input/examples`.
+pub fn extract_examples(root_dir: &str, year: i16, day: u32) -> Result<Examples, InputError> {
+    let input_dir = Path::new(root_dir).join("input");
+    let account = account_of(&session_from_env(&input_dir)?);
+    let page_path = InputCache::from_env(root_dir).page_path(&account, year, day);
+    extract_from(root_dir, day, &page_path, |cached| fetch_page(&input_dir, &page_path, year, day, cached))
+}
+
+/// `extract_examples` for the page cached at `page_path`, with `fetch` getting
+/// a fresh copy given whatever is cached.
+fn extract_from(
+    root_dir: &str,
+    day: u32,
+    page_path: &Path,
+    fetch: impl FnOnce(Option<String>) -> Result<String, InputError>,
+) -> Result<Examples, InputError> {
+    let html = match std::fs::read_to_string(page_path) {
+        Ok(html) if html.matches(DAY_DESC).count() >= 2 => html,
+        cached => fetch(cached.ok())?,
+    };
+    let examples = Examples::from_page(&html);
+    examples.write(&examples_dir(root_dir), day)?;
+    Ok(examples)
+}
+
+#[cfg(feature = "download")]
+fn fetch_page(
+    input_dir: &Path,
+    page_path: &Path,
+    year: i16,
+    day: u32,
+    _cached: Option<String>,
+) -> Result<String, InputError> {
+    let client = crate::AocClient::from_env(input_dir)?;
+    let html = client.fetch_puzzle_page(year, day)?;
+    let io_error = |source| InputError::Io {
+        path: page_path.to_path_buf(),
+        source,
+    };
+    std::fs::create_dir_all(page_path.parent().unwrap_or(Path::new("."))).map_err(io_error)?;
+    std::fs::write(page_path, &html).map_err(io_error)?;
+    Ok(html)
+}
+
+/// Without the `download` feature a page that's already saved is the best we
+/// can do, even one that doesn't show part 2.
+#[cfg(not(feature = "download"))]
+fn fetch_page(
+    _input_dir: &Path,
+    page_path: &Path,
+    _year: i16,
+    _day: u32,
+    cached: Option<String>,
+) -> Result<String, InputError> {
+    cached.ok_or_else(|| InputError::NotCached(page_path.to_path_buf()))
+}
+
+/// The `k`th (1-based) example of a day, as written by `extract_examples`.
+pub fn read_example(root_dir: &str, day: u32, k: usize) -> Result<Input, InputError> {
+    let path = examples_dir(root_dir).join(format!("day{day}_{k}.txt"));
+    std::fs::read_to_string(&path).map(Input::new).map_err(|source| InputError::Io { path, source })
+}
+
+/// The expected answers recorded for a day's examples.
+pub fn read_example_answers(root_dir: &str, day: u32) -> Result<Vec<ExampleAnswer>, InputError> {
+    let path = examples_dir(root_dir).join(format!("day{day}_answers.tsv"));
+    let text = std::fs::read_to_string(&path).map_err(|source| InputError::Io { path, source })?;
+    Ok(text
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, '\t');
+            Some(ExampleAnswer {
+                part: fields.next()?.parse().ok()?,
+                example: fields.next()?.parse().ok()?,
+                answer: fields.next()?.to_string(),
+            })
+        })
+        .collect())
+}
+
+/// Drops tags (the site highlights parts of examples with `<em>`) and decodes
+/// the handful of entities that show up in puzzle text.
+pub(crate) fn html_text(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::temp_dir;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2>
+<p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+</code></pre>
+<p>In this example, the calibration values of these four lines are <code>12</code>, <code>38</code>, <code>15</code>, and <code>77</code>. Adding these together produces <code><em>142</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>54632</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>two1nine
+x&lt;<em>y</em>&gt;z &amp; more
+</code></pre>
+<p>Adding these together produces <code><em>281</em></code>.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_from_page() {
+        let examples = Examples::from_page(PAGE);
+        assert_eq!(
+            vec![
+                "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n".to_string(),
+                "two1nine\nx<y>z & more\n".to_string(),
+            ],
+            examples.blocks
+        );
+        assert_eq!(
+            vec![
+                ExampleAnswer { part: 1, example: 1, answer: "142".into() },
+                ExampleAnswer { part: 2, example: 2, answer: "281".into() },
+            ],
+            examples.answers
+        );
+        assert_eq!(Examples::default(), Examples::from_page("<html>Please log in</html>"));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let root = temp_dir("examples_round_trip");
+        let page_path = root.join("cache").join("day1.html");
+        std::fs::create_dir_all(root.join("cache")).unwrap();
+        std::fs::write(&page_path, PAGE).unwrap();
+        let root = root.to_str().unwrap();
+
+        let examples = extract_from(root, 1, &page_path, |_| panic!("part 2 is already cached")).unwrap();
+        assert_eq!(examples.blocks[0], read_example(root, 1, 1).unwrap());
+        assert_eq!(examples.blocks[1], read_example(root, 1, 2).unwrap());
+        assert!(read_example(root, 1, 3).is_err());
+        assert_eq!(examples.answers, read_example_answers(root, 1).unwrap());
+    }
+
+    #[test]
+    fn test_refresh_for_part2() {
+        let root = temp_dir("examples_refresh");
+        let page_path = root.join("day1.html");
+        let part1 = &PAGE[..PAGE.find("<p>Your puzzle answer").unwrap()];
+        std::fs::write(&page_path, part1).unwrap();
+        let root = root.to_str().unwrap();
+
+        let examples = extract_from(root, 1, &page_path, |cached| {
+            assert_eq!(Some(part1), cached.as_deref());
+            Ok(PAGE.to_string())
+        })
+        .unwrap();
+        assert_eq!(2, examples.blocks.len());
+
+        std::fs::remove_file(&page_path).unwrap();
+        let mut fetched = false;
+        extract_from(root, 1, &page_path, |cached| {
+            assert_eq!(None, cached);
+            fetched = true;
+            Ok(PAGE.to_string())
+        })
+        .unwrap();
+        assert!(fetched);
+    }
+}
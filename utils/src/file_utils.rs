@@ -1,49 +1,246 @@
-use reqwest::{blocking::Client, header::COOKIE};
 use std::fs::read_to_string;
 use std::path::{Path, PathBuf};
+use thiserror::Error;
 
-pub fn read_aoc_impl(root_dir: &str, src_filename: &str) -> String {
-    let mut p = PathBuf::from(root_dir);
-    p.push("input");
-    p.push(Path::new(src_filename).file_stem().unwrap());
-    p.set_extension("txt");
-    if !p.exists() {
-        download_file(root_dir, &p);
-    }
-    read_to_string(p).unwrap()
+#[cfg(feature = "download")]
+use crate::AocClient;
+use crate::{format_wait, Anomaly, Input};
+
+#[derive(Error, Debug)]
+pub enum InputError {
+    #[error("failed to access {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("can't work out the year from crate directory {0:?}, expected advent_<year>")]
+    UnknownYear(PathBuf),
+    #[error("can't work out the day from input file {0:?}, expected day<N>.txt")]
+    UnknownDay(PathBuf),
+    #[error("no session found: set {SESSION_ENV_VAR} or put it in one of {searched:?}")]
+    MissingSession { searched: Vec<PathBuf> },
+    #[error("input not cached, enable the download feature or place the file at {0}")]
+    NotCached(PathBuf),
+    #[cfg(feature = "download")]
+    #[error("request to {url} failed: {source}")]
+    Request { url: String, source: reqwest::Error },
+    #[cfg(feature = "download")]
+    #[error("{url} returned {status}: {body}")]
+    BadStatus {
+        url: String,
+        status: reqwest::StatusCode,
+        body: String,
+    },
+    #[error("{url} did not return puzzle input: {body}")]
+    BadBody { url: String, body: String },
+    #[error("could not make sense of the reply from {url}: {body}")]
+    UnexpectedReply { url: String, body: String },
+    #[error("{origin} is not in canonical form: {}", join_anomalies(anomalies))]
+    NotCanonical { origin: String, anomalies: Vec<Anomaly> },
+    #[error("day {day} of {year} unlocks in {}, pass --wait to wait for it", format_wait(*remaining))]
+    NotUnlocked {
+        year: i16,
+        day: u32,
+        remaining: std::time::Duration,
+    },
+}
+
+fn join_anomalies(anomalies: &[Anomaly]) -> String {
+    anomalies.iter().map(Anomaly::to_string).collect::<Vec<_>>().join(", ")
 }
 
-fn download_file(root_dir: &str, target_filename: &Path) {
-    let year: i16 = Path::new(root_dir)
+/// Backs the `read_aoc!` macro, which only knows the crate directory and the
+/// source file: the year comes from the directory name, the day from the file
+/// name, so `day5.rs` and variants like `day5_alt.rs` both read day 5.
+pub fn read_aoc_impl(root_dir: &str, src_filename: &str) -> Result<Input, InputError> {
+    let day = Path::new(src_filename)
         .file_stem()
-        .unwrap()
-        .to_str()
-        .unwrap()
-        .strip_prefix("advent_")
-        .unwrap()
-        .parse()
-        .unwrap();
-    let cookie_path = target_filename.parent().unwrap().join("cookie.txt");
-    let cookie = read_to_string(&cookie_path).unwrap().strip_suffix("\n").unwrap().to_string();
-    let day: i8 = target_filename
+        .and_then(|s| s.to_str())
+        .and_then(day_from_stem)
+        .ok_or_else(|| InputError::UnknownDay(src_filename.into()))?;
+    read_aoc_day(root_dir, year_from_root(root_dir)?, day)
+}
+
+/// A day's input: the shared cache for the current session's account,
+/// downloading it on a miss when built with the `download` feature. Like
+/// every input read here, it comes back normalized.
+///
+/// Only the account's own input is ever used; run with `--input` or
+/// `--profile` to try a file from anywhere else.
+pub fn read_aoc_day(root_dir: &str, year: i16, day: u32) -> Result<Input, InputError> {
+    #[cfg(feature = "download")]
+    {
+        let client = AocClient::from_env(&Path::new(root_dir).join("input"))?;
+        InputCache::from_env(root_dir).get_or_download(&client, year, day).map(Input::new)
+    }
+    #[cfg(not(feature = "download"))]
+    {
+        let account = account_of(&session_from_env(&Path::new(root_dir).join("input"))?);
+        let cache = InputCache::from_env(root_dir);
+        cache
+            .get(&account, year, day)
+            .map(Input::new)
+            .ok_or_else(|| InputError::NotCached(cache.path(&account, year, day)))
+    }
+}
+
+/// A day's input if the current session's account already has it cached,
+/// never touching the network.
+pub fn cached_input(root_dir: &str, year: i16, day: u32) -> Option<Input> {
+    let session = session_from_env(&Path::new(root_dir).join("input")).ok()?;
+    InputCache::from_env(root_dir).get(&account_of(&session), year, day).map(Input::new)
+}
+
+/// The name for the current session's own inputs, whose answers live in
+/// `input/answers.tsv`, as opposed to a named profile.
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Directories under `input/` that hold something other than a profile.
+const RESERVED_INPUT_DIRS: [&str; 3] = ["cache", "examples", "submissions"];
+
+/// The named input profiles of a year, one per `input/<profile>/` directory.
+/// Each holds another person's `day<N>.txt` files and `answers.tsv`, so every
+/// solution can be checked against more than one input.
+pub fn profiles(root_dir: &str) -> Result<Vec<String>, InputError> {
+    let dir = Path::new(root_dir).join("input");
+    let entries = match std::fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(source) => return Err(InputError::Io { path: dir, source }),
+    };
+    let mut profiles: Vec<_> = entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_ok_and(|t| t.is_dir()))
+        .filter_map(|e| e.file_name().into_string().ok())
+        .filter(|name| !name.starts_with('.') && !RESERVED_INPUT_DIRS.contains(&name.as_str()))
+        .collect();
+    profiles.sort();
+    Ok(profiles)
+}
+
+/// The directory a profile's inputs and answers live in.
+pub fn profile_dir(root_dir: &str, profile: &str) -> PathBuf {
+    match profile {
+        DEFAULT_PROFILE => Path::new(root_dir).join("input"),
+        profile => Path::new(root_dir).join("input").join(profile),
+    }
+}
+
+/// A day's input from a named profile. Profiles are only ever filled in by
+/// hand, so there's nothing to download.
+pub fn read_profile_input(root_dir: &str, profile: &str, day: u32) -> Result<Input, InputError> {
+    let path = profile_dir(root_dir, profile).join(format!("day{day}.txt"));
+    read_to_string(&path).map(Input::new).map_err(|source| InputError::Io { path, source })
+}
+
+/// `day5` and `day5_alt` are both day 5.
+fn day_from_stem(stem: &str) -> Option<u32> {
+    let digits = stem.strip_prefix("day")?;
+    let end = digits.find(|c: char| !c.is_ascii_digit()).unwrap_or(digits.len());
+    match &digits[end..] {
+        rest if rest.is_empty() || rest.starts_with('_') => digits[..end].parse().ok(),
+        _ => None,
+    }
+}
+
+pub const CACHE_DIR_ENV_VAR: &str = "AOC_CACHE_DIR";
+
+/// Downloaded inputs, stored as `<dir>/<account>/<year>/day<N>.txt` with the
+/// puzzle page next to each as `day<N>.html`. Both differ between accounts, so
+/// each session gets its own subdirectory.
+pub struct InputCache {
+    dir: PathBuf,
+}
+
+impl InputCache {
+    pub fn new(dir: impl Into<PathBuf>) -> InputCache {
+        InputCache { dir: dir.into() }
+    }
+
+    /// `AOC_CACHE_DIR`, `$XDG_CACHE_HOME/aoc` or `~/.cache/aoc`, falling back
+    /// to `<root_dir>/input/cache` when there's no home directory.
+    pub fn from_env(root_dir: &str) -> InputCache {
+        let dir = std::env::var_os(CACHE_DIR_ENV_VAR)
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("XDG_CACHE_HOME").map(|d| Path::new(&d).join("aoc")))
+            .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".cache").join("aoc")))
+            .unwrap_or_else(|| Path::new(root_dir).join("input").join("cache"));
+        InputCache::new(dir)
+    }
+
+    pub fn path(&self, account: &str, year: i16, day: u32) -> PathBuf {
+        self.dir.join(account).join(year.to_string()).join(format!("day{day}.txt"))
+    }
+
+    pub fn page_path(&self, account: &str, year: i16, day: u32) -> PathBuf {
+        self.path(account, year, day).with_extension("html")
+    }
+
+    pub fn get(&self, account: &str, year: i16, day: u32) -> Option<String> {
+        read_to_string(self.path(account, year, day)).ok()
+    }
+}
+
+pub const SESSION_ENV_VAR: &str = "AOC_SESSION";
+
+/// Session from `AOC_SESSION`, the per-user config file or
+/// `<input_dir>/cookie.txt`, in that order.
+pub fn session_from_env(input_dir: &Path) -> Result<String, InputError> {
+    let mut paths: Vec<PathBuf> = config_session_path().into_iter().collect();
+    paths.push(input_dir.join("cookie.txt"));
+    find_session(std::env::var(SESSION_ENV_VAR).ok(), &paths)
+}
+
+/// The session as a cookie header value, whether or not it was stored with
+/// the `session=` prefix.
+pub(crate) fn session_cookie(session: &str) -> String {
+    let session = session.trim();
+    if session.starts_with("session=") {
+        session.to_string()
+    } else {
+        format!("session={session}")
+    }
+}
+
+/// A stable name for the account behind a session, for keeping its inputs
+/// apart from other accounts' without writing the session to disk.
+pub fn account_of(session: &str) -> String {
+    // FNV-1a, which unlike `DefaultHasher` won't change between Rust releases.
+    let hash = session_cookie(session)
+        .bytes()
+        .fold(0xcbf29ce484222325u64, |h, b| (h ^ b as u64).wrapping_mul(0x100000001b3));
+    format!("{hash:016x}")
+}
+
+/// `$XDG_CONFIG_HOME/aoc/session`, falling back to `~/.config/aoc/session`.
+fn config_session_path() -> Option<PathBuf> {
+    let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+    Some(config_dir.join("aoc").join("session"))
+}
+
+fn find_session(env_value: Option<String>, paths: &[PathBuf]) -> Result<String, InputError> {
+    if let Some(session) = env_value.filter(|s| !s.trim().is_empty()) {
+        return Ok(session);
+    }
+    paths
+        .iter()
+        .filter_map(|p| read_to_string(p).ok())
+        .find(|s| !s.trim().is_empty())
+        .ok_or_else(|| InputError::MissingSession {
+            searched: paths.to_vec(),
+        })
+}
+
+/// The year of an `advent_<year>` crate directory.
+pub fn year_from_root(root_dir: &str) -> Result<i16, InputError> {
+    Path::new(root_dir)
         .file_stem()
-        .unwrap()
-        .to_str()
-        .unwrap()
-        .get(3..)
-        .unwrap()
-        .parse()
-        .unwrap();
-    let url = format!("https://adventofcode.com/{year}/day/{day}/input");
-    let client = Client::new();
-    let result = client
-        .get(url)
-        .header(COOKIE, cookie)
-        .send()
-        .unwrap()
-        .text()
-        .unwrap();
-    std::fs::write(target_filename, &result).unwrap();
+        .and_then(|s| s.to_str())
+        .and_then(|s| s.strip_prefix("advent_"))
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(|| InputError::UnknownYear(root_dir.into()))
 }
 
 #[macro_export]
@@ -51,4 +248,77 @@ macro_rules! read_aoc {
     () => {
         utils::read_aoc_impl(env!("CARGO_MANIFEST_DIR"), file!())
     };
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::temp_dir;
+    #[test]
+    fn test_unknown_day() {
+        let err = read_aoc_impl("advent_2023", "src/bin/template.rs");
+        assert!(matches!(err, Err(InputError::UnknownDay(_))));
+        let err = read_aoc_impl("utils", "src/bin/day1.rs");
+        assert!(matches!(err, Err(InputError::UnknownYear(_))));
+        assert_eq!(Some(5), day_from_stem("day5"));
+        assert_eq!(Some(5), day_from_stem("day5_alt"));
+        assert_eq!(Some(17), day_from_stem("day17_2"));
+        assert_eq!(None, day_from_stem("day5x"));
+        assert_eq!(None, day_from_stem("template"));
+    }
+    #[test]
+    fn test_find_session() {
+        let dir = temp_dir("find_session");
+        let config = dir.join("session");
+        let cookie = dir.join("cookie.txt");
+        let paths = [config.clone(), cookie.clone()];
+
+        assert!(matches!(
+            find_session(None, &paths),
+            Err(InputError::MissingSession { .. })
+        ));
+        std::fs::write(&cookie, "session=from_cookie").unwrap();
+        assert_eq!("session=from_cookie", find_session(None, &paths).unwrap());
+        std::fs::write(&config, "from_config\n").unwrap();
+        assert_eq!("from_config\n", find_session(None, &paths).unwrap());
+        assert_eq!("from_env", find_session(Some("from_env".into()), &paths).unwrap());
+        assert_eq!("from_config\n", find_session(Some(" ".into()), &paths).unwrap());
+    }
+    #[cfg(not(feature = "download"))]
+    #[test]
+    fn test_not_cached() {
+        let root = temp_dir("not_cached");
+        std::fs::create_dir_all(root.join("input")).unwrap();
+        std::fs::write(root.join("input").join("day1.txt"), "someone else's\n").unwrap();
+        match read_aoc_day(root.to_str().unwrap(), 1999, 1).unwrap_err() {
+            InputError::NotCached(path) => assert!(path.ends_with("1999/day1.txt")),
+            InputError::MissingSession { .. } => {}
+            err => panic!("unexpected error {err}"),
+        }
+        let err = InputError::NotCached(InputCache::new("/cache").path("abc", 1999, 1));
+        assert_eq!(
+            "input not cached, enable the download feature or place the file at /cache/abc/1999/day1.txt",
+            err.to_string()
+        );
+    }
+    #[test]
+    fn test_account_of() {
+        assert_eq!(account_of("abc"), account_of("session=abc\n"));
+        assert_ne!(account_of("abc"), account_of("abd"));
+        assert_eq!(16, account_of("abc").len());
+    }
+    #[test]
+    fn test_profiles() {
+        let root = temp_dir("profiles");
+        for dir in ["alice", "bob", "examples", "submissions", ".git"] {
+            std::fs::create_dir_all(root.join("input").join(dir)).unwrap();
+        }
+        std::fs::write(root.join("input").join("bob").join("day3.txt"), "3\r\n").unwrap();
+        std::fs::write(root.join("input").join("notes.txt"), "").unwrap();
+        let root = root.to_str().unwrap();
+        assert_eq!(vec!["alice", "bob"], profiles(root).unwrap());
+        assert_eq!("3\n", read_profile_input(root, "bob", 3).unwrap());
+        assert!(read_profile_input(root, "alice", 3).is_err());
+        assert!(profiles("/nonexistent").unwrap().is_empty());
+    }
+}
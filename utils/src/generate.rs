@@ -0,0 +1,156 @@
+//! Seeded generators of puzzle-shaped inputs at any size, for seeing how a
+//! solution scales past the one size the real input comes in.
+
+use std::ops::RangeInclusive;
+
+use crate::Rng;
+
+/// A `width` x `height` grid of characters drawn from `weights`, each with
+/// probability proportional to its weight, one line per row.
+pub fn char_grid(rng: &mut Rng, width: usize, height: usize, weights: &[(char, u32)]) -> String {
+    let total: u32 = weights.iter().map(|&(_, w)| w).sum();
+    assert!(total > 0, "no characters to draw from");
+    let mut grid = String::with_capacity((width + 1) * height);
+    for _ in 0..height {
+        for _ in 0..width {
+            let mut pick = rng.below(total as u64) as u32;
+            let &(c, _) = weights
+                .iter()
+                .find(|&&(_, w)| {
+                    let found = pick < w;
+                    pick = pick.saturating_sub(w);
+                    found
+                })
+                .unwrap();
+            grid.push(c);
+        }
+        grid.push('\n');
+    }
+    grid
+}
+
+/// Digits 1 to 9, like the heat loss map of 2023 day 17.
+pub fn digit_grid(rng: &mut Rng, width: usize, height: usize) -> String {
+    let digits: Vec<_> = ('1'..='9').map(|c| (c, 1)).collect();
+    char_grid(rng, width, height, &digits)
+}
+
+/// Round (`O`) and cube (`#`) rocks on `.`, like 2023 day 14. `round` and
+/// `cube` are the fraction of cells holding each kind.
+pub fn rock_grid(rng: &mut Rng, width: usize, height: usize, round: f64, cube: f64) -> String {
+    assert!(round + cube <= 1.0, "more rocks than cells");
+    let per_mille = |f: f64| (f * 1000.0).round() as u32;
+    // Rounding each fraction on its own can push the pair past 1000 even
+    // though their sum fits.
+    let rocks = (per_mille(round) + per_mille(cube)).min(1000);
+    let weights = [
+        ('O', per_mille(round)),
+        ('#', rocks - per_mille(round)),
+        ('.', 1000 - rocks),
+    ];
+    char_grid(rng, width, height, &weights)
+}
+
+/// A forest (`#`) maze with paths (`.`) like 2023 day 23: a `junctions` x
+/// `junctions` lattice of crossings joined by straight paths, entered at the
+/// second cell of the top row and left at the second to last cell of the
+/// bottom row. Each gap between neighbouring rows or columns of crossings is
+/// drawn from `gaps`, and paths get `>` and `v` slopes next to crossings,
+/// pointing away from the entrance.
+pub fn junction_maze(rng: &mut Rng, junctions: usize, gaps: RangeInclusive<i64>) -> String {
+    assert!(junctions > 0 && *gaps.start() >= 2, "crossings need forest between them");
+    let xs = spaced(rng, 1, junctions, &gaps);
+    let first_row = rng.range(gaps.clone()) as usize;
+    let ys = spaced(rng, first_row, junctions, &gaps);
+    let width = xs[junctions - 1] + 2;
+    let height = ys[junctions - 1] + rng.range(gaps.clone()) as usize + 1;
+
+    let mut grid = vec![vec![b'#'; width]; height];
+    for (row, &y) in ys.iter().enumerate() {
+        for (col, &x) in xs.iter().enumerate() {
+            if col + 1 < junctions {
+                let next = xs[col + 1];
+                grid[y][x..=next].fill(b'.');
+                grid[y][x + 1] = b'>';
+                grid[y][next - 1] = b'>';
+            }
+            if row + 1 < junctions {
+                let next = ys[row + 1];
+                (y..=next).for_each(|y| grid[y][x] = b'.');
+                grid[y + 1][x] = b'v';
+                grid[next - 1][x] = b'v';
+            }
+        }
+    }
+    // The entrance runs down into the first crossing and the exit down out of
+    // the last one.
+    (0..ys[0]).for_each(|y| grid[y][1] = b'.');
+    (ys[junctions - 1] + 1..height).for_each(|y| grid[y][width - 2] = b'.');
+
+    let mut maze = String::with_capacity((width + 1) * height);
+    for row in grid {
+        maze.extend(row.into_iter().map(char::from));
+        maze.push('\n');
+    }
+    maze
+}
+
+/// `count` positions from `start` on, a random gap apart.
+fn spaced(rng: &mut Rng, start: usize, count: usize, gaps: &RangeInclusive<i64>) -> Vec<usize> {
+    let mut at = start;
+    let mut positions = vec![at];
+    for _ in 1..count {
+        at += rng.range(gaps.clone()) as usize;
+        positions.push(at);
+    }
+    positions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grids() {
+        let grid = digit_grid(&mut Rng::new(5), 7, 3);
+        assert_eq!(grid, digit_grid(&mut Rng::new(5), 7, 3));
+        assert_eq!(vec![7; 3], grid.lines().map(str::len).collect::<Vec<_>>());
+        assert!(grid.lines().flat_map(str::chars).all(|c| ('1'..='9').contains(&c)));
+
+        let rocks = rock_grid(&mut Rng::new(5), 100, 100, 0.2, 0.1);
+        let count = |c| rocks.chars().filter(|&r| r == c).count();
+        assert!((1500..2500).contains(&count('O')), "{}", count('O'));
+        assert!((500..1500).contains(&count('#')), "{}", count('#'));
+        assert_eq!(10000, count('O') + count('#') + count('.'));
+        assert_eq!("...\n", rock_grid(&mut Rng::new(5), 3, 1, 0.0, 0.0));
+        // 0.2225 and 0.7775 add up to 1, but round to 223 and 778 per mille.
+        let full = rock_grid(&mut Rng::new(5), 50, 50, 0.2225, 0.7775);
+        assert!(!full.contains('.'));
+    }
+
+    #[test]
+    fn test_junction_maze() {
+        let maze = junction_maze(&mut Rng::new(1), 2, 2..=2);
+        assert_eq!(
+            "\
+#.###
+#.###
+#.>.#
+#v#v#
+#.>.#
+###.#
+###.#
+",
+            maze
+        );
+
+        let maze = junction_maze(&mut Rng::new(9), 5, 3..=8);
+        let rows: Vec<_> = maze.lines().map(str::as_bytes).collect();
+        let (width, height) = (rows[0].len(), rows.len());
+        assert!(rows.iter().all(|r| r.len() == width));
+        assert_eq!(b'.', rows[0][1]);
+        assert_eq!(b'.', rows[height - 1][width - 2]);
+        let open = rows.iter().flat_map(|r| r.iter()).filter(|&&c| c != b'#').count();
+        assert!(open > 4 * 5 * 3 * 2);
+    }
+}
@@ -1,5 +1,7 @@
 use std::ops::{Index, IndexMut};
 
+use thiserror::Error;
+
 use crate::Point;
 
 pub type Coord<const RANK: usize> = Point<i64, RANK>;
@@ -25,6 +27,12 @@ impl<T: Clone, const RANK: usize> Grid<T, RANK> {
             multipliers,
         }
     }
+    pub fn get_data(&self) -> &Vec<T> {
+        &self.data
+    }
+}
+
+impl<T, const RANK: usize> Grid<T, RANK> {
     pub fn from_data(data: Vec<T>, dims: &[i64; RANK]) -> Grid<T, RANK> {
         let mut cum_product = 1;
         let multipliers = std::array::from_fn(|i| {
@@ -39,12 +47,6 @@ impl<T: Clone, const RANK: usize> Grid<T, RANK> {
             multipliers,
         }
     }
-    pub fn get_data(&self) -> &Vec<T> {
-        &self.data
-    }
-}
-
-impl<T, const RANK: usize> Grid<T, RANK> {
     pub fn contains<U: Into<Coord<RANK>>>(&self, coord: U) -> bool {
         self.dims
             .iter()
@@ -63,6 +65,53 @@ impl<T, const RANK: usize> Grid<T, RANK> {
         self.dims
     }
 
+    /// Every coordinate on the grid, in storage order: axis 0 changes
+    /// fastest, so a 2D grid goes left to right along each row, top row
+    /// first. `iter`, `iter_mut`, `enumerate` and `enumerate_mut` all use
+    /// this order.
+    pub fn iter_coords(&self) -> impl Iterator<Item = Coord<RANK>> + use<T, RANK> {
+        coords(self.dims)
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    pub fn enumerate(&self) -> impl Iterator<Item = (Coord<RANK>, &T)> {
+        self.iter_coords().zip(&self.data)
+    }
+
+    pub fn enumerate_mut(&mut self) -> impl Iterator<Item = (Coord<RANK>, &mut T)> {
+        self.iter_coords().zip(&mut self.data)
+    }
+
+    /// The cells in line with `through` along `axis`, from coordinate 0 up.
+    pub fn line(&self, axis: usize, through: impl Into<Coord<RANK>>) -> impl Iterator<Item = (Coord<RANK>, &T)> {
+        let through = through.into();
+        assert!(self.contains(through), "{through:?} out of bounds");
+        (0..self.dims[axis]).map(move |i| {
+            let mut coord = through;
+            coord[axis] = i;
+            (coord, &self[coord])
+        })
+    }
+
+    /// The cells with coordinate `index` on `axis`, such as a row or a column
+    /// in 2D or a layer in 3D, in storage order.
+    pub fn axis_slice(&self, axis: usize, index: i64) -> impl Iterator<Item = (Coord<RANK>, &T)> {
+        assert!((0..self.dims[axis]).contains(&index), "{index} out of bounds on axis {axis}");
+        let mut dims = self.dims;
+        dims[axis] = 1;
+        coords(dims).map(move |mut coord| {
+            coord[axis] = index;
+            (coord, &self[coord])
+        })
+    }
+
     fn convert_index<U: Into<Coord<RANK>>>(&self, coord: U) -> Option<usize> {
         let coord = coord.into();
         if !self.contains(coord) {
@@ -73,25 +122,135 @@ impl<T, const RANK: usize> Grid<T, RANK> {
             .into_iter()
             .zip(coord.as_ref())
             .fold(0, |acc: i64, (x, y)| acc + x * *y);
-        return Some(dot_product as usize);
+        Some(dot_product as usize)
     }
 }
 
+/// Every coordinate of a box with the given `dims`, axis 0 fastest.
+fn coords<const RANK: usize>(dims: [i64; RANK]) -> impl Iterator<Item = Coord<RANK>> {
+    let total = if dims.iter().all(|&d| d > 0) { dims.iter().product() } else { 0 };
+    (0..total).map(move |mut i| {
+        let coord: [i64; RANK] = std::array::from_fn(|axis| {
+            let x = i % dims[axis];
+            i /= dims[axis];
+            x
+        });
+        coord.into()
+    })
+}
+
 impl Grid<bool, 2> {
     pub fn print(&self) {
-        for row in 0..self.dims[1] {
-            let s: String = (0..self.dims[0])
-                .map(|col| if self[[col, row]] { '*' } else { '.' })
-                .collect();
-            println!("{}", s);
-        }
+        print!("{}", self.render());
     }
 
 }
-impl <T> Grid<T, 2> {
-    pub fn iter_coords(&self) -> impl Iterator<Item = Coord<2>> {
-        (0..self.get_dims()[0])
-            .flat_map(move |col| (0..self.get_dims()[1]).map(move |row| Coord::from([col, row])))
+
+/// Why a block of text isn't a grid. Lines and columns count from 1, the way
+/// an editor shows them.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum GridError<E> {
+    #[error("no rows to make a grid from")]
+    Empty,
+    #[error("line {line} is {found} characters wide, expected {expected} like line 1")]
+    Ragged { line: usize, expected: usize, found: usize },
+    #[error("line {line}, column {column}: {error}")]
+    Cell { line: usize, column: usize, error: E },
+}
+
+/// The usual mapper error: a character the day has no meaning for.
+#[derive(Clone, Copy, Debug, Error, PartialEq, Eq)]
+#[error("unexpected character {:?}", char::from(*.0))]
+pub struct UnknownCell(pub u8);
+
+/// Where the marker characters asked for in `Grid::parse_with_markers` were
+/// found, in reading order.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Markers {
+    found: Vec<(u8, Coord<2>)>,
+}
+
+impl Markers {
+    pub fn all(&self, marker: u8) -> impl Iterator<Item = Coord<2>> + '_ {
+        self.found.iter().filter(move |(m, _)| *m == marker).map(|(_, c)| *c)
+    }
+
+    /// The first (often the only) place `marker` appears.
+    pub fn first(&self, marker: u8) -> Option<Coord<2>> {
+        self.all(marker).next()
+    }
+}
+
+impl<T> Grid<T, 2> {
+    /// Reads a rectangular block of text, one row per line, calling `cell`
+    /// with each byte and its `[col, row]`. A trailing newline (or `\r\n`)
+    /// is fine; rows of different widths are not.
+    pub fn parse<E>(text: &str, cell: impl FnMut(u8, Coord<2>) -> Result<T, E>) -> Result<Self, GridError<E>> {
+        Self::parse_with_markers(text, &[], cell).map(|(grid, _)| grid)
+    }
+
+    /// Like `parse`, but also notes where each of `markers` (such as `S` for
+    /// a start) appears. Marker cells still go through `cell`, which decides
+    /// what lies under them.
+    pub fn parse_with_markers<E>(
+        text: &str,
+        markers: &[u8],
+        mut cell: impl FnMut(u8, Coord<2>) -> Result<T, E>,
+    ) -> Result<(Self, Markers), GridError<E>> {
+        let lines: Vec<_> = text.split_terminator('\n').map(|l| l.strip_suffix('\r').unwrap_or(l)).collect();
+        let width = lines.first().map_or(0, |l| l.len());
+        if width == 0 {
+            return Err(GridError::Empty);
+        }
+        let mut data = Vec::with_capacity(width * lines.len());
+        let mut found = Markers::default();
+        for (row, line) in lines.iter().enumerate() {
+            if line.len() != width {
+                return Err(GridError::Ragged {
+                    line: row + 1,
+                    expected: width,
+                    found: line.len(),
+                });
+            }
+            for (col, b) in line.bytes().enumerate() {
+                let coord = Coord::from([col as i64, row as i64]);
+                if markers.contains(&b) {
+                    found.found.push((b, coord));
+                }
+                let value = cell(b, coord).map_err(|error| GridError::Cell {
+                    line: row + 1,
+                    column: col + 1,
+                    error,
+                })?;
+                data.push(value);
+            }
+        }
+        Ok((Grid::from_data(data, &[width as i64, lines.len() as i64]), found))
+    }
+
+    /// Row `y`, left to right. Rows are contiguous in storage.
+    pub fn row(&self, y: i64) -> &[T] {
+        assert!((0..self.dims[1]).contains(&y), "row {y} out of bounds");
+        let width = self.dims[0] as usize;
+        &self.data[y as usize * width..][..width]
+    }
+
+    pub fn row_mut(&mut self, y: i64) -> &mut [T] {
+        assert!((0..self.dims[1]).contains(&y), "row {y} out of bounds");
+        let width = self.dims[0] as usize;
+        &mut self.data[y as usize * width..][..width]
+    }
+
+    /// Every row, top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.data.chunks(self.dims[0].max(1) as usize)
+    }
+
+    /// Column `x`, top to bottom. Empty on a grid with no rows.
+    pub fn column(&self, x: i64) -> impl Iterator<Item = &T> {
+        assert!((0..self.dims[0]).contains(&x), "column {x} out of bounds");
+        let start = (x as usize).min(self.data.len());
+        self.data[start..].iter().step_by(self.dims[0] as usize)
     }
 }
 
@@ -126,6 +285,89 @@ mod tests {
         assert!(!grid.contains(coord));
         assert!(!grid.contains([-1, 0, 0]));
     }
+    #[test]
+    fn test_parse() {
+        let lab = "....#\r\n.#^..\r\n";
+        let (grid, markers) = Grid::parse_with_markers(lab, b"^", |b, _| match b {
+            b'#' => Ok(true),
+            b'.' | b'^' => Ok(false),
+            b => Err(UnknownCell(b)),
+        })
+        .unwrap();
+        assert_eq!([5, 2], grid.get_dims());
+        assert!(grid[[4, 0]] && grid[[1, 1]] && !grid[[2, 1]]);
+        assert_eq!(Some(Coord::from([2, 1])), markers.first(b'^'));
+        assert_eq!(None, markers.first(b'S'));
+
+        let digits = Grid::parse("12\n34", |b, coord| Ok::<_, UnknownCell>((b - b'0', coord[0])));
+        assert_eq!((3, 0), digits.unwrap()[[0, 1]]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let bool_cell = |b, _| match b {
+            b'#' => Ok(true),
+            b'.' => Ok(false),
+            b => Err(UnknownCell(b)),
+        };
+        assert_eq!(Err(GridError::Empty), Grid::parse("", bool_cell));
+        assert_eq!(
+            Err(GridError::Ragged {
+                line: 3,
+                expected: 2,
+                found: 3
+            }),
+            Grid::parse("..\n##\n...\n", bool_cell)
+        );
+        let err = Grid::parse("..\n#x\n", bool_cell).unwrap_err();
+        assert_eq!("line 2, column 2: unexpected character 'x'", err.to_string());
+    }
+
+    #[test]
+    fn test_storage_order() {
+        let mut grid = Grid::from_data((0..24).collect(), &[2, 3, 4]);
+        let coords: Vec<_> = grid.iter_coords().collect();
+        assert_eq!(24, coords.len());
+        for (i, (coord, &value)) in grid.enumerate().enumerate() {
+            assert_eq!(coords[i], coord);
+            assert_eq!(Some(i), grid.convert_index(coord));
+            assert_eq!(i, value as usize);
+        }
+        assert_eq!(Coord::from([1, 0, 0]), coords[1]);
+        assert_eq!(Coord::from([0, 1, 0]), coords[2]);
+        assert!(grid.iter().copied().eq(0..24));
+
+        for (coord, value) in grid.enumerate_mut() {
+            *value = coord[2];
+        }
+        grid.iter_mut().for_each(|value| *value *= 10);
+        assert_eq!(30, grid[[1, 2, 3]]);
+        assert_eq!(0, Grid::new(0, &[3, 0]).iter_coords().count());
+    }
+
+    #[test]
+    fn test_slices() {
+        // 0 1 2
+        // 3 4 5
+        let mut grid = Grid::from_data((0..6).collect(), &[3, 2]);
+        assert_eq!(&[3, 4, 5], grid.row(1));
+        assert_eq!(vec![&[0, 1, 2][..], &[3, 4, 5]], grid.rows().collect::<Vec<_>>());
+        assert_eq!(vec![&2, &5], grid.column(2).collect::<Vec<_>>());
+        let flat = Grid::new(0, &[3, 0]);
+        assert_eq!(0, flat.column(2).count());
+        assert_eq!(0, flat.rows().count());
+        grid.row_mut(0)[1] = 9;
+        let column: Vec<_> = grid.line(1, [1, 1]).collect();
+        assert_eq!(vec![(Coord::from([1, 0]), &9), (Coord::from([1, 1]), &4)], column);
+
+        let cube = Grid::from_data((0..24).collect(), &[2, 3, 4]);
+        let layer: Vec<_> = cube.axis_slice(1, 2).collect();
+        assert_eq!(8, layer.len());
+        assert!(layer.iter().all(|&(coord, &v)| coord[1] == 2 && cube.convert_index(coord) == Some(v as usize)));
+        assert!(layer.windows(2).all(|w| w[0].1 < w[1].1));
+        assert_eq!(vec![1, 3, 5], cube.line(1, [1, 0, 0]).map(|(_, &v)| v).collect::<Vec<_>>());
+    }
+
     #[test]
     fn index_map() {
         let grid = Grid::new(0i32, &[3, 4, 5]);
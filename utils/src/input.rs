@@ -0,0 +1,179 @@
+use std::fmt;
+use std::ops::Deref;
+
+use crate::InputError;
+
+/// Something about a raw input that `Input::new` had to fix.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Anomaly {
+    ByteOrderMark,
+    /// How many lines ended in `\r\n`.
+    CrLf(usize),
+    MissingFinalNewline,
+    /// How many empty lines followed the last line with anything on it.
+    TrailingBlankLines(usize),
+}
+
+impl fmt::Display for Anomaly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Anomaly::ByteOrderMark => write!(f, "a byte order mark"),
+            Anomaly::CrLf(n) => write!(f, "{n} CRLF line endings"),
+            Anomaly::MissingFinalNewline => write!(f, "no newline at the end"),
+            Anomaly::TrailingBlankLines(n) => write!(f, "{n} blank lines at the end"),
+        }
+    }
+}
+
+/// Puzzle input in canonical shape: `\n` line endings, every line (the last
+/// one included) ending in exactly one `\n`, and no blank lines after the
+/// last line. Parsers can rely on `input.len() / (width + 1)` being the
+/// number of rows and on the final byte being the last line's `\n`.
+///
+/// Derefs to `str`, so it can be passed anywhere a day expects `&str`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Input {
+    text: String,
+    anomalies: Vec<Anomaly>,
+}
+
+impl Input {
+    /// Normalizes `raw`, remembering what had to change.
+    pub fn new(raw: impl Into<String>) -> Input {
+        let mut text = raw.into();
+        let mut anomalies = vec![];
+        if let Some(rest) = text.strip_prefix('\u{feff}') {
+            text = rest.to_string();
+            anomalies.push(Anomaly::ByteOrderMark);
+        }
+        let crlf = text.matches("\r\n").count();
+        if crlf > 0 {
+            text = text.replace("\r\n", "\n");
+            anomalies.push(Anomaly::CrLf(crlf));
+        }
+        let content = text.trim_end_matches('\n').len();
+        match (content, text.len() - content) {
+            (0, 0) | (1.., 1) => {}
+            (0, newlines) => anomalies.push(Anomaly::TrailingBlankLines(newlines)),
+            (_, 0) => anomalies.push(Anomaly::MissingFinalNewline),
+            (_, newlines) => anomalies.push(Anomaly::TrailingBlankLines(newlines - 1)),
+        }
+        text.truncate(content);
+        if content > 0 {
+            text.push('\n');
+        }
+        Input { text, anomalies }
+    }
+
+    /// What normalizing changed, empty if the raw input was already canonical.
+    pub fn anomalies(&self) -> &[Anomaly] {
+        &self.anomalies
+    }
+
+    /// Strict mode: fails, naming `origin`, if normalizing changed anything.
+    pub fn strict(self, origin: &str) -> Result<Input, InputError> {
+        if self.anomalies.is_empty() {
+            return Ok(self);
+        }
+        Err(InputError::NotCanonical {
+            origin: origin.to_string(),
+            anomalies: self.anomalies,
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn into_string(self) -> String {
+        self.text
+    }
+}
+
+impl Deref for Input {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.text
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+impl From<&str> for Input {
+    fn from(raw: &str) -> Input {
+        Input::new(raw)
+    }
+}
+
+impl From<String> for Input {
+    fn from(raw: String) -> Input {
+        Input::new(raw)
+    }
+}
+
+impl PartialEq<str> for Input {
+    fn eq(&self, other: &str) -> bool {
+        self.text == other
+    }
+}
+
+impl PartialEq<&str> for Input {
+    fn eq(&self, other: &&str) -> bool {
+        self.text == *other
+    }
+}
+
+impl PartialEq<Input> for &str {
+    fn eq(&self, other: &Input) -> bool {
+        *self == other.text
+    }
+}
+
+impl PartialEq<Input> for String {
+    fn eq(&self, other: &Input) -> bool {
+        *self == other.text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        for (raw, text, anomalies) in [
+            ("1 2\n3 4\n", "1 2\n3 4\n", vec![]),
+            ("", "", vec![]),
+            ("1 2\n3 4", "1 2\n3 4\n", vec![Anomaly::MissingFinalNewline]),
+            ("1 2\r\n3 4\r\n", "1 2\n3 4\n", vec![Anomaly::CrLf(2)]),
+            ("1 2\n3 4\n\n\n", "1 2\n3 4\n", vec![Anomaly::TrailingBlankLines(2)]),
+            ("\n\n", "", vec![Anomaly::TrailingBlankLines(2)]),
+            // Blank lines in the middle separate sections, so they stay.
+            ("a\n\nb\n", "a\n\nb\n", vec![]),
+            (
+                "\u{feff}a\r\nb\r\n\r\n",
+                "a\nb\n",
+                vec![Anomaly::ByteOrderMark, Anomaly::CrLf(3), Anomaly::TrailingBlankLines(1)],
+            ),
+        ] {
+            let input = Input::new(raw);
+            assert_eq!(text, input, "{raw:?}");
+            assert_eq!(anomalies, input.anomalies(), "{raw:?}");
+        }
+    }
+
+    #[test]
+    fn test_strict() {
+        assert_eq!("a\n", Input::new("a\n").strict("day 1").unwrap());
+        let err = Input::new("a\r\nb").strict("day 1").unwrap_err();
+        assert_eq!(
+            "day 1 is not in canonical form: 1 CRLF line endings, no newline at the end",
+            err.to_string()
+        );
+    }
+}
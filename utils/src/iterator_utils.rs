@@ -92,7 +92,7 @@ pub trait IteratorExts : Iterator {
 
     fn chunked_iterator(self) -> impl Iterator<Item=Vec<Self::Item>> where Self::Item : AsRef<str>, Self: Sized {
         let mut v = vec![];
-        self.map(|x| Some(x)).chain(std::iter::once(None)).filter_map(move |x| {
+        self.map(Some).chain(std::iter::once(None)).filter_map(move |x| {
             let Some(x) = x else { return Some(std::mem::take(&mut v)) };
             if x.as_ref() == "" {
                 Some(std::mem::take(&mut v))
@@ -1,14 +1,84 @@
 mod err_utils;
 pub use err_utils::{OptionEmptyError, OptionUtils};
 
+mod input;
+pub use input::{Anomaly, Input};
+
 mod file_utils;
-pub use file_utils::read_aoc_impl;
+pub use file_utils::{
+    account_of, cached_input, profile_dir, profiles, read_aoc_day, read_aoc_impl, read_profile_input,
+    session_from_env, year_from_root, InputCache, InputError, CACHE_DIR_ENV_VAR, DEFAULT_PROFILE, SESSION_ENV_VAR,
+};
+
+#[cfg(feature = "download")]
+mod client;
+#[cfg(feature = "download")]
+pub use client::{AocClient, BASE_URL_ENV_VAR, DEFAULT_BASE_URL, DEFAULT_MIN_INTERVAL};
+
+mod unlock;
+pub use unlock::{ensure_unlocked, format_wait, time_until_unlock, unlock_time, wait_for_unlock};
+
+mod examples;
+pub use examples::{extract_examples, read_example, read_example_answers, ExampleAnswer, Examples};
+
+mod submit;
+pub use submit::{Attempt, Ledger, Outcome, Refusal, Verdict};
+#[cfg(feature = "download")]
+pub use submit::submit_answer;
+
+#[cfg(test)]
+mod test_server;
+
+mod alloc_count;
+pub use alloc_count::{count_allocs, AllocStats, CountingAlloc};
+
+mod bench;
+pub use bench::{
+    compare, format_bytes, format_ns, format_report, load_baseline, measure_solution, save_results, visit_phases,
+    BenchOptions, Comparison, Measurement, Phase, PhaseVisitor,
+};
+
+mod rng;
+pub use rng::{seed_from_env, Rng, SEED_ENV_VAR};
+
+mod generate;
+pub use generate::{char_grid, digit_grid, junction_maze, rock_grid};
+
+mod differential;
+pub use differential::{shrink_int, shrink_vec, Differential, Disagreement, DEFAULT_CASES, DEFAULT_SEED};
+
+mod solution;
+pub use solution::{Day, Solution};
+
+mod regression;
+pub use regression::{check_days, check_year, format_table, AnswerKey, CheckRow, Status};
+
+mod dashboard;
+pub use dashboard::{format_dashboard, time_days, time_year, Dashboard, Timing};
+
+mod args;
+pub use args::{InputSource, RunArgs, USAGE};
+
+mod scaffold;
+pub use scaffold::{new_day, prefetch};
+
+mod runner;
+pub use runner::aoc_main;
 
 mod point;
 pub use point::Point;
 
 mod grid;
-pub use grid::{Coord, Grid};
+pub use grid::{Coord, Grid, GridError, Markers, UnknownCell};
+
+mod render;
+pub use render::{CellChar, Render};
+
+mod neighbors;
+pub use neighbors::{moore, von_neumann};
+
+mod view;
+pub use view::{GridView, GridViewMut, View};
 
 mod iterator_utils;
 pub use iterator_utils::IteratorExts;
@@ -18,4 +88,4 @@ pub use slotmap::{SlotMap, SlotKey};
 
 // Commonly used containers
 pub use std::collections::{HashSet, HashMap};
-pub use std::cmp::{min,max};
\ No newline at end of file
+pub use std::cmp::{min,max};
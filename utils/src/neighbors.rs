@@ -0,0 +1,154 @@
+use crate::{Coord, Grid};
+
+/// Offsets to the `2 * RANK` cells that share a face with a cell (its von
+/// Neumann neighbourhood): `+1` then `-1` along each axis in turn, so in 2D
+/// right, left, down, up.
+pub fn von_neumann<const RANK: usize>() -> impl Iterator<Item = Coord<RANK>> + Clone {
+    (0..2 * RANK).map(|i| {
+        let mut offset = [0; RANK];
+        offset[i / 2] = if i % 2 == 0 { 1 } else { -1 };
+        offset.into()
+    })
+}
+
+/// Offsets to the `3^RANK - 1` cells that touch a cell at all (its Moore
+/// neighbourhood), in reading order: the first axis changes fastest, so in 2D
+/// the row above left to right, then left and right, then the row below.
+pub fn moore<const RANK: usize>() -> impl Iterator<Item = Coord<RANK>> + Clone {
+    (0..3usize.pow(RANK as u32)).filter(|&i| i != 3usize.pow(RANK as u32) / 2).map(|i| {
+        let mut rest = i;
+        let offset: [i64; RANK] = std::array::from_fn(|_| {
+            let d = (rest % 3) as i64 - 1;
+            rest /= 3;
+            d
+        });
+        offset.into()
+    })
+}
+
+impl<T, const RANK: usize> Grid<T, RANK> {
+    /// The cells at `coord + offset` that are on the grid, with their values.
+    pub fn neighbors<'a, U: Into<Coord<RANK>>>(
+        &'a self,
+        coord: impl Into<Coord<RANK>>,
+        offsets: impl IntoIterator<Item = U> + 'a,
+    ) -> impl Iterator<Item = (Coord<RANK>, &'a T)> + 'a {
+        let coord = coord.into();
+        offsets.into_iter().filter_map(move |offset| {
+            let n = coord + offset;
+            self.get(n).map(|t| (n, t))
+        })
+    }
+
+    /// Calls `f` on each cell at `coord + offset` that is on the grid.
+    pub fn neighbors_mut<U: Into<Coord<RANK>>>(
+        &mut self,
+        coord: impl Into<Coord<RANK>>,
+        offsets: impl IntoIterator<Item = U>,
+        mut f: impl FnMut(Coord<RANK>, &mut T),
+    ) {
+        let coord = coord.into();
+        for offset in offsets {
+            let n = coord + offset;
+            if let Some(t) = self.get_mut(n) {
+                f(n, t);
+            }
+        }
+    }
+
+    /// Like `neighbors`, but treats the grid as tiling space forever, as for
+    /// an infinitely repeating map. Every offset yields its unwrapped
+    /// coordinate together with the value of the tile it lands on.
+    pub fn neighbors_wrapping<'a, U: Into<Coord<RANK>>>(
+        &'a self,
+        coord: impl Into<Coord<RANK>>,
+        offsets: impl IntoIterator<Item = U> + 'a,
+    ) -> impl Iterator<Item = (Coord<RANK>, &'a T)> + 'a {
+        let coord = coord.into();
+        offsets.into_iter().map(move |offset| {
+            let n = coord + offset;
+            (n, &self[self.wrap(n)])
+        })
+    }
+
+    /// `coord` moved onto the grid by whole grid widths along each axis.
+    pub fn wrap(&self, coord: impl Into<Coord<RANK>>) -> Coord<RANK> {
+        let coord = coord.into();
+        let dims = self.get_dims();
+        std::array::from_fn(|i| coord[i].rem_euclid(dims[i])).into()
+    }
+
+    pub fn von_neumann(&self, coord: impl Into<Coord<RANK>>) -> impl Iterator<Item = (Coord<RANK>, &T)> {
+        self.neighbors(coord, von_neumann())
+    }
+
+    pub fn moore(&self, coord: impl Into<Coord<RANK>>) -> impl Iterator<Item = (Coord<RANK>, &T)> {
+        self.neighbors(coord, moore())
+    }
+}
+
+impl<T> Grid<T, 2> {
+    /// The in-bounds cells right, left, below and above `coord`.
+    pub fn neighbors4(&self, coord: impl Into<Coord<2>>) -> impl Iterator<Item = (Coord<2>, &T)> {
+        self.von_neumann(coord)
+    }
+
+    /// The in-bounds cells around `coord`, diagonals included, in reading order.
+    pub fn neighbors8(&self, coord: impl Into<Coord<2>>) -> impl Iterator<Item = (Coord<2>, &T)> {
+        self.moore(coord)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn offsets<const RANK: usize>(it: impl Iterator<Item = Coord<RANK>>) -> Vec<[i64; RANK]> {
+        it.map(|c| *c.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_offsets() {
+        assert_eq!(vec![[1, 0], [-1, 0], [0, 1], [0, -1]], offsets(von_neumann::<2>()));
+        assert_eq!(
+            vec![[-1, -1], [0, -1], [1, -1], [-1, 0], [1, 0], [-1, 1], [0, 1], [1, 1]],
+            offsets(moore::<2>())
+        );
+        assert_eq!(6, von_neumann::<3>().count());
+        assert_eq!(26, moore::<3>().count());
+        assert!(moore::<3>().all(|c| c != Coord::from([0, 0, 0])));
+        assert_eq!(vec![[1], [-1]], offsets(von_neumann::<1>()));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        // 0 1 2
+        // 3 4 5
+        let mut grid = Grid::from_data((0..6).collect(), &[3, 2]);
+        let values = |it: &mut dyn Iterator<Item = (Coord<2>, &i32)>| it.map(|(_, v)| *v).collect::<Vec<_>>();
+        assert_eq!(vec![1, 3], values(&mut grid.neighbors4([0, 0])));
+        assert_eq!(vec![5, 3, 1], values(&mut grid.neighbors4([1, 1])));
+        assert_eq!(vec![0, 1, 2, 3, 5], values(&mut grid.neighbors8([1, 1])));
+        assert_eq!(vec![(Coord::from([2, 1]), &5)], grid.neighbors([1, 0], [[1, 1], [5, 5]]).collect::<Vec<_>>());
+
+        grid.neighbors_mut([0, 0], moore(), |_, v| *v *= 10);
+        assert_eq!(&vec![0, 10, 2, 30, 40, 5], grid.get_data());
+
+        let wrapped: Vec<_> = grid.neighbors_wrapping([0, 0], von_neumann()).collect();
+        assert_eq!(
+            vec![
+                (Coord::from([1, 0]), &10),
+                (Coord::from([-1, 0]), &2),
+                (Coord::from([0, 1]), &30),
+                (Coord::from([0, -1]), &30)
+            ],
+            wrapped
+        );
+        assert_eq!(Coord::from([2, 0]), grid.wrap([-4, 6]));
+
+        let cube = Grid::new(1u8, &[3, 3, 3]);
+        assert_eq!(26, cube.moore([1, 1, 1]).count());
+        assert_eq!(7, cube.moore([0, 0, 0]).count());
+        assert_eq!(3, cube.von_neumann([0, 0, 0]).count());
+    }
+}
@@ -0,0 +1,295 @@
+use std::collections::BTreeMap;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+
+use crate::{cached_input, profile_dir, profiles, read_profile_input, Day, Input, InputError, DEFAULT_PROFILE};
+
+/// Known good answers for one input profile of a year, one
+/// `day<TAB>part<TAB>answer` line each.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AnswerKey {
+    answers: BTreeMap<(u32, u8), String>,
+}
+
+impl AnswerKey {
+    pub fn path_for(root_dir: &str, profile: &str) -> PathBuf {
+        profile_dir(root_dir, profile).join("answers.tsv")
+    }
+
+    pub fn load(path: &Path) -> Result<AnswerKey, InputError> {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(source) => {
+                return Err(InputError::Io {
+                    path: path.into(),
+                    source,
+                })
+            }
+        };
+        let answers = text
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.splitn(3, '\t');
+                let day = fields.next()?.parse().ok()?;
+                let part = fields.next()?.parse().ok()?;
+                Some(((day, part), fields.next()?.to_string()))
+            })
+            .collect();
+        Ok(AnswerKey { answers })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), InputError> {
+        let text: String = self
+            .answers
+            .iter()
+            .map(|((day, part), answer)| format!("{day}\t{part}\t{answer}\n"))
+            .collect();
+        std::fs::write(path, text).map_err(|source| InputError::Io {
+            path: path.into(),
+            source,
+        })
+    }
+
+    pub fn get(&self, day: u32, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(|s| s.as_str())
+    }
+
+    pub fn insert(&mut self, day: u32, part: u8, answer: String) {
+        self.answers.insert((day, part), answer);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    /// The day panicked, so there is no answer. Counts as a failure.
+    Panicked,
+    /// No cached input, or no recorded answer to compare against.
+    Missing,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CheckRow {
+    pub profile: String,
+    pub day: u32,
+    pub part: u8,
+    pub expected: Option<String>,
+    pub actual: Option<String>,
+    pub status: Status,
+}
+
+/// Runs every day that has a cached input and compares both parts against the
+/// key. A day that panics gets `Status::Panicked` for both parts, with no
+/// actual answer, rather than aborting the whole check.
+pub fn check_days(
+    days: &[Day],
+    profile: &str,
+    key: &AnswerKey,
+    input: impl Fn(u32) -> Option<String>,
+) -> Vec<CheckRow> {
+    let mut rows = vec![];
+    for day in days {
+        // `Some(None)` when there was an input but solving it panicked.
+        let answers = input(day.day).map(|input| catch_unwind(AssertUnwindSafe(|| day.solve(&input))).ok());
+        for part in [1, 2] {
+            let expected = key.get(day.day, part).map(str::to_string);
+            let actual = answers.as_ref().and_then(|a| a.as_ref()).map(|a| a[part as usize - 1].clone());
+            let status = match (&expected, &answers, &actual) {
+                (_, Some(None), _) => Status::Panicked,
+                (Some(e), _, Some(a)) if e == a => Status::Pass,
+                (Some(_), _, Some(_)) => Status::Fail,
+                _ => Status::Missing,
+            };
+            rows.push(CheckRow {
+                profile: profile.to_string(),
+                day: day.day,
+                part,
+                expected,
+                actual,
+                status,
+            });
+        }
+    }
+    rows
+}
+
+/// `check_days` against the year's cached inputs and `input/answers.tsv`, then
+/// against every profile's inputs and answers under `input/<profile>/`. With
+/// `record`, answers for parts that have none yet are added to each key;
+/// parts that panicked are never recorded.
+pub fn check_year(root_dir: &str, year: i16, days: &[Day], record: bool) -> Result<Vec<CheckRow>, InputError> {
+    check_profiles(root_dir, days, record, |day| cached_input(root_dir, year, day).map(Input::into_string))
+}
+
+/// `check_year` with `puzzle_input` standing in for the session's cached
+/// inputs.
+fn check_profiles(
+    root_dir: &str,
+    days: &[Day],
+    record: bool,
+    puzzle_input: impl Fn(u32) -> Option<String>,
+) -> Result<Vec<CheckRow>, InputError> {
+    let mut rows = vec![];
+    for profile in std::iter::once(DEFAULT_PROFILE.to_string()).chain(profiles(root_dir)?) {
+        let key_path = AnswerKey::path_for(root_dir, &profile);
+        let mut key = AnswerKey::load(&key_path)?;
+        let profile_rows = check_days(days, &profile, &key, |day| match profile.as_str() {
+            DEFAULT_PROFILE => puzzle_input(day),
+            profile => read_profile_input(root_dir, profile, day).ok().map(Input::into_string),
+        });
+        if record {
+            for row in &profile_rows {
+                if let (None, Some(actual)) = (&row.expected, &row.actual) {
+                    key.insert(row.day, row.part, actual.clone());
+                }
+            }
+            key.save(&key_path)?;
+        }
+        rows.extend(profile_rows);
+    }
+    Ok(rows)
+}
+
+pub fn format_table(rows: &[CheckRow]) -> String {
+    let width = |f: &dyn Fn(&CheckRow) -> usize| rows.iter().map(f).max().unwrap_or(0);
+    let profile_width = width(&|r| r.profile.len()).max(7);
+    let expected_width = width(&|r| r.expected.as_deref().map_or(1, str::len)).max(8);
+    let actual_width = width(&|r| r.actual.as_deref().map_or(1, str::len)).max(6);
+    let mut table = format!(
+        "{:<profile_width$}  {:>3}  {:>4}  {:<expected_width$}  {:<actual_width$}  status\n",
+        "profile", "day", "part", "expected", "actual"
+    );
+    for row in rows {
+        let status = match row.status {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Panicked => "PANIC",
+            Status::Missing => "missing",
+        };
+        table += &format!(
+            "{:<profile_width$}  {:>3}  {:>4}  {:<expected_width$}  {:<actual_width$}  {status}\n",
+            row.profile,
+            row.day,
+            row.part,
+            row.expected.as_deref().unwrap_or("-"),
+            row.actual.as_deref().unwrap_or("-"),
+        );
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::temp_dir;
+    use crate::Solution;
+
+    struct Echo;
+
+    impl Solution for Echo {
+        type Parsed<'a> = &'a str;
+
+        fn parse(input: &str) -> Self::Parsed<'_> {
+            input.trim_end()
+        }
+        fn part1(data: &Self::Parsed<'_>) -> impl std::fmt::Display {
+            data.len()
+        }
+        fn part2(data: &Self::Parsed<'_>) -> impl std::fmt::Display {
+            if data.is_empty() {
+                panic!("no input");
+            }
+            data.to_uppercase()
+        }
+    }
+
+    const DAYS: &[Day] = &[Day::new::<Echo>(1), Day::new::<Echo>(2), Day::new::<Echo>(3)];
+
+    #[test]
+    fn test_check_days() {
+        let mut key = AnswerKey::default();
+        key.insert(1, 1, "3".into());
+        key.insert(1, 2, "ABD".into());
+        key.insert(2, 1, "0".into());
+        key.insert(3, 1, "5".into());
+        let input = |day| match day {
+            1 => Some("abc".to_string()),
+            2 => Some("".to_string()),
+            _ => None,
+        };
+        let statuses: Vec<_> = check_days(DAYS, DEFAULT_PROFILE, &key, input)
+            .into_iter()
+            .map(|r| (r.day, r.part, r.actual, r.status))
+            .collect();
+        assert_eq!(
+            vec![
+                (1, 1, Some("3".into()), Status::Pass),
+                (1, 2, Some("ABC".into()), Status::Fail),
+                (2, 1, None, Status::Panicked),
+                (2, 2, None, Status::Panicked),
+                (3, 1, None, Status::Missing),
+                (3, 2, None, Status::Missing),
+            ],
+            statuses
+        );
+    }
+
+    #[test]
+    fn test_check_year_record() {
+        let root = temp_dir("check_year");
+        let input_dir = root.join("input");
+        std::fs::create_dir_all(&input_dir).unwrap();
+        std::fs::write(input_dir.join("answers.tsv"), "1\t1\t3\n").unwrap();
+        // Someone else's input left in `input/` is never the puzzle input.
+        std::fs::write(input_dir.join("day3.txt"), "abc").unwrap();
+        let root = root.to_str().unwrap();
+        let cached = |day| ["abc", ""].get(day as usize - 1).map(|s| s.to_string());
+
+        let rows = check_profiles(root, &DAYS[..1], true, cached).unwrap();
+        assert_eq!(vec![Status::Pass, Status::Missing], rows.iter().map(|r| r.status).collect::<Vec<_>>());
+        let key = AnswerKey::load(&AnswerKey::path_for(root, DEFAULT_PROFILE)).unwrap();
+        assert_eq!(Some("ABC"), key.get(1, 2));
+
+        let rows = check_profiles(root, &DAYS[..1], false, cached).unwrap();
+        assert!(rows.iter().all(|r| r.status == Status::Pass));
+        assert!(format_table(&rows).contains("  1     2  ABC       ABC     pass\n"));
+
+        // Day 2's empty input panics, which must not become its answer.
+        let rows = check_profiles(root, &DAYS[1..3], true, cached).unwrap();
+        assert_eq!(
+            vec![Status::Panicked, Status::Panicked, Status::Missing, Status::Missing],
+            rows.iter().map(|r| r.status).collect::<Vec<_>>()
+        );
+        let key = AnswerKey::load(&AnswerKey::path_for(root, DEFAULT_PROFILE)).unwrap();
+        assert_eq!((None, None), (key.get(2, 1), key.get(2, 2)));
+        assert_eq!(None, key.get(3, 1));
+        assert!(format_table(&rows).contains("PANIC"));
+    }
+
+    #[test]
+    fn test_check_year_profiles() {
+        let root = temp_dir("check_year_profiles");
+        let bob = root.join("input").join("bob");
+        std::fs::create_dir_all(&bob).unwrap();
+        std::fs::create_dir_all(root.join("input").join("examples")).unwrap();
+        std::fs::write(bob.join("day1.txt"), "abcd").unwrap();
+        std::fs::write(bob.join("answers.tsv"), "1\t1\t3\n").unwrap();
+        let root = root.to_str().unwrap();
+
+        let rows = check_year(root, 2023, &DAYS[..1], true).unwrap();
+        let statuses: Vec<_> = rows.iter().map(|r| (r.profile.as_str(), r.part, r.status)).collect();
+        assert_eq!(
+            vec![
+                ("default", 1, Status::Missing),
+                ("default", 2, Status::Missing),
+                ("bob", 1, Status::Fail),
+                ("bob", 2, Status::Missing),
+            ],
+            statuses
+        );
+        assert_eq!(Some("ABCD"), AnswerKey::load(&AnswerKey::path_for(root, "bob")).unwrap().get(1, 2));
+        assert!(format_table(&rows).contains("bob        1     1  3         4       FAIL\n"));
+    }
+}
@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::io;
+
+use crate::{Coord, Grid};
+
+/// How a cell looks when its grid is drawn as text.
+pub trait CellChar {
+    fn cell_char(&self) -> char;
+}
+
+impl CellChar for char {
+    fn cell_char(&self) -> char {
+        *self
+    }
+}
+
+impl CellChar for bool {
+    fn cell_char(&self) -> char {
+        if *self { '*' } else { '.' }
+    }
+}
+
+/// Digits as themselves, anything bigger as `+`.
+impl CellChar for u8 {
+    fn cell_char(&self) -> char {
+        char::from_digit(*self as u32, 10).unwrap_or('+')
+    }
+}
+
+/// A grid on its way to being drawn, from `Grid::render` or
+/// `Grid::render_with`. Rows are lines, top to bottom, and it prints through
+/// `Display` or straight into any `io::Write`.
+pub struct Render<'a, T> {
+    grid: &'a Grid<T, 2>,
+    cell: Box<dyn Fn(&T) -> char + 'a>,
+    overlay: HashMap<Coord<2>, char>,
+    rulers: bool,
+}
+
+impl<T: CellChar> Grid<T, 2> {
+    pub fn render(&self) -> Render<'_, T> {
+        self.render_with(T::cell_char)
+    }
+}
+
+impl<T> Grid<T, 2> {
+    pub fn render_with<'a>(&'a self, cell: impl Fn(&T) -> char + 'a) -> Render<'a, T> {
+        Render {
+            grid: self,
+            cell: Box::new(cell),
+            overlay: HashMap::new(),
+            rulers: false,
+        }
+    }
+}
+
+impl<T> Render<'_, T> {
+    /// Draws `mark` over the cells at `coords`, such as a path or a loop.
+    /// Later overlays cover earlier ones; coordinates off the grid are ignored.
+    pub fn overlay(mut self, mark: char, coords: impl IntoIterator<Item = Coord<2>>) -> Self {
+        self.overlay.extend(coords.into_iter().map(|c| (c, mark)));
+        self
+    }
+
+    /// Column numbers above the grid (tens over units once it's wider than
+    /// ten) and row numbers down the left.
+    pub fn with_rulers(mut self) -> Self {
+        self.rulers = true;
+        self
+    }
+
+    pub fn write_to(&self, out: &mut impl io::Write) -> io::Result<()> {
+        write!(out, "{self}")
+    }
+}
+
+impl<T> fmt::Display for Render<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [width, height] = self.grid.get_dims();
+        let margin = if self.rulers { (height - 1).max(0).to_string().len() + 1 } else { 0 };
+        if self.rulers {
+            if width > 10 {
+                let tens: String = (0..width)
+                    .map(|col| if col < 10 { ' ' } else { digit(col / 10) })
+                    .collect();
+                writeln!(f, "{:margin$}{}", "", tens.trim_end())?;
+            }
+            let units: String = (0..width).map(digit).collect();
+            writeln!(f, "{:margin$}{units}", "")?;
+        }
+        for row in 0..height {
+            if self.rulers {
+                write!(f, "{:>w$} ", row, w = margin - 1)?;
+            }
+            let line: String = (0..width)
+                .map(|col| {
+                    let coord = Coord::from([col, row]);
+                    self.overlay
+                        .get(&coord)
+                        .copied()
+                        .unwrap_or_else(|| (self.cell)(&self.grid[coord]))
+                })
+                .collect();
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The last decimal digit of `n`.
+fn digit(n: i64) -> char {
+    char::from_digit((n % 10) as u32, 10).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let grid = Grid::parse("#..\n.#.\n", |b, _| Ok::<_, ()>(b == b'#')).unwrap();
+        assert_eq!("*..\n.*.\n", grid.render().to_string());
+        assert_eq!("#  \n # \n", grid.render_with(|&b| if b { '#' } else { ' ' }).to_string());
+
+        let path = [[1, 0], [2, 0], [2, 1], [9, 9]].map(Coord::from);
+        let rendered = grid.render().overlay('o', path).overlay('S', [Coord::from([2, 1])]);
+        assert_eq!("*oo\n.*S\n", rendered.to_string());
+
+        let mut out = vec![];
+        grid.render().with_rulers().write_to(&mut out).unwrap();
+        assert_eq!("  012\n0 *..\n1 .*.\n", String::from_utf8(out).unwrap());
+    }
+
+    #[test]
+    fn test_rulers() {
+        let grid = Grid::new(1u8, &[12, 11]);
+        let rendered = grid.render().with_rulers().to_string();
+        let lines: Vec<_> = rendered.lines().collect();
+        assert_eq!("             11", lines[0]);
+        assert_eq!("   012345678901", lines[1]);
+        assert_eq!(" 0 111111111111", lines[2]);
+        assert_eq!("10 111111111111", lines[12]);
+    }
+}
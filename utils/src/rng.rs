@@ -0,0 +1,83 @@
+use std::ops::RangeInclusive;
+
+/// Overrides the seed of randomized tests, to replay or explore cases.
+pub const SEED_ENV_VAR: &str = "AOC_SEED";
+
+/// A small seeded generator (SplitMix64). Nothing cryptographic, but a seed
+/// gives the same numbers on every platform and toolchain, so a failing case
+/// can always be replayed from its seed.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `0..n`, give or take a bias too small to matter here.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "empty range");
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (lo, hi) = range.into_inner();
+        assert!(lo <= hi, "empty range");
+        match hi.wrapping_sub(lo) as u64 {
+            u64::MAX => self.next_u64() as i64,
+            span => lo.wrapping_add(self.below(span + 1) as i64),
+        }
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+}
+
+/// `SEED_ENV_VAR` if it's set to a number, else `default`.
+pub fn seed_from_env(default: u64) -> u64 {
+    std::env::var(SEED_ENV_VAR)
+        .ok()
+        .and_then(|s| s.trim().parse().ok())
+        .unwrap_or(default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        let first: Vec<_> = (0..5).map(|_| a.next_u64()).collect();
+        assert_eq!(first, (0..5).map(|_| b.next_u64()).collect::<Vec<_>>());
+        assert_ne!(first[0], Rng::new(8).next_u64());
+        // SplitMix64's published first output for seed 0.
+        assert_eq!(0xe220_a839_7b1d_cdaf, Rng::new(0).next_u64());
+
+        let mut rng = Rng::new(1);
+        for _ in 0..1000 {
+            assert!((-3..=3).contains(&rng.range(-3..=3)));
+            assert!(rng.below(10) < 10);
+        }
+        assert_eq!(5, rng.range(5..=5));
+        assert!(!rng.chance(0.0) && rng.chance(1.0));
+        assert_eq!(&'x', rng.choose(&['x']));
+    }
+}
@@ -0,0 +1,261 @@
+use std::io::Read;
+use std::time::SystemTime;
+
+use anyhow::{anyhow, bail, Context, Result};
+
+use crate::{
+    check_year, compare, extract_examples, format_dashboard, format_report, format_table, format_wait,
+    load_baseline, new_day, prefetch, read_aoc_day, read_example, read_example_answers, read_profile_input,
+    save_results, time_until_unlock, time_year, wait_for_unlock, BenchOptions, Day, Input, InputSource, Measurement,
+    RunArgs, Status, USAGE,
+};
+
+/// Entry point shared by each year's `aoc_<year>` binary: `aoc_2023 17` runs
+/// one day, `aoc_2023 all` runs every registered day in order, `aoc_2023 check`
+/// compares every day against the recorded answers, `aoc_2023 time` prints how
+/// long each day takes, and `aoc_2023 new 18` sets up the next day from the
+/// template.
+pub fn aoc_main(root_dir: &str, year: i16, days: &[Day]) -> Result<()> {
+    let mut args = std::env::args().skip(1);
+    let selection = args.next().ok_or_else(|| anyhow!(USAGE))?;
+    if selection == "check" {
+        let record = match args.next().as_deref() {
+            None => false,
+            Some("--record") => true,
+            Some(other) => bail!("unexpected argument {other:?}\n{USAGE}"),
+        };
+        return check(root_dir, year, days, record);
+    }
+    if selection == "new" {
+        let (day, wait) = match (args.next().map(|d| d.parse()), args.next().as_deref()) {
+            (Some(Ok(day)), None) => (day, false),
+            (Some(Ok(day)), Some("--wait")) => (day, true),
+            _ => bail!("expected a day number\n{USAGE}"),
+        };
+        return new(root_dir, year, day, wait);
+    }
+    if selection == "time" {
+        let parallel = match args.next().as_deref() {
+            None => false,
+            Some("--parallel") => true,
+            Some(other) => bail!("unexpected argument {other:?}\n{USAGE}"),
+        };
+        print!("{}", format_dashboard(&time_year(root_dir, year, days, parallel)));
+        return Ok(());
+    }
+    run(root_dir, year, days, &RunArgs::parse(selection, args)?)
+}
+
+fn run(root_dir: &str, year: i16, days: &[Day], args: &RunArgs) -> Result<()> {
+    let mut measurements = vec![];
+    for day in select_days(days, &args.selection)? {
+        if args.wait {
+            wait_until_unlocked(year, day.day);
+        }
+        if args.examples {
+            let found = extract_examples(root_dir, year, day.day)?;
+            println!(
+                "Day {}: extracted {} examples and {} answers",
+                day.day,
+                found.blocks.len(),
+                found.answers.len()
+            );
+        }
+        let input = read_input(root_dir, year, day.day, &args.input, args.strict)?;
+        let parts = match args.part {
+            Some(part) => vec![(part, day.solve_part(&input, part))],
+            None => [1, 2].into_iter().zip(day.solve(&input)).collect(),
+        };
+        if !args.quiet {
+            println!("Day {}", day.day);
+        }
+        for (part, answer) in &parts {
+            if args.quiet {
+                println!("{answer}");
+                continue;
+            }
+            match expected_answer(root_dir, day.day, *part, &args.input) {
+                Some(expected) if expected == *answer => println!("  part {part}: {answer} (as expected)"),
+                Some(expected) => println!("  part {part}: {answer} (expected {expected})"),
+                None => println!("  part {part}: {answer}"),
+            }
+        }
+        if let Some(options) = &args.bench {
+            measurements.extend(day.benchmark(&input, options));
+        }
+        if let Some(part) = args.submit {
+            let (_, answer) = parts.iter().find(|(p, _)| *p == part).unwrap();
+            submit(root_dir, year, day.day, part, answer, &args.input, &input)?;
+        }
+    }
+    if let Some(options) = &args.bench {
+        report_bench(root_dir, &measurements, options, args.save_baseline)?;
+    }
+    Ok(())
+}
+
+/// The normalized input, or with `strict` an error if it needed normalizing.
+fn read_input(root_dir: &str, year: i16, day: u32, source: &InputSource, strict: bool) -> Result<Input> {
+    let input = match source {
+        InputSource::Puzzle => read_aoc_day(root_dir, year, day)?,
+        InputSource::File(path) => Input::new(
+            std::fs::read_to_string(path).with_context(|| format!("could not read {}", path.display()))?,
+        ),
+        InputSource::Stdin => {
+            let mut input = String::new();
+            std::io::stdin().read_to_string(&mut input).context("could not read stdin")?;
+            Input::new(input)
+        }
+        InputSource::Example(k) => read_example(root_dir, day, *k)?,
+        InputSource::Profile(profile) => read_profile_input(root_dir, profile, day)?,
+    };
+    if !strict {
+        return Ok(input);
+    }
+    let origin = match source {
+        InputSource::Puzzle => format!("the day {day} input"),
+        InputSource::File(path) => path.display().to_string(),
+        InputSource::Stdin => "standard input".to_string(),
+        InputSource::Example(k) => format!("example {k} of day {day}"),
+        InputSource::Profile(profile) => format!("the day {day} input of profile {profile}"),
+    };
+    Ok(input.strict(&origin)?)
+}
+
+/// The answer the puzzle page gives for an example, if that's what we ran.
+fn expected_answer(root_dir: &str, day: u32, part: u8, source: &InputSource) -> Option<String> {
+    let InputSource::Example(k) = source else {
+        return None;
+    };
+    read_example_answers(root_dir, day)
+        .ok()?
+        .into_iter()
+        .find(|a| a.part == part && a.example == *k)
+        .map(|a| a.answer)
+}
+
+/// Prints the timings next to the stored baseline, writes them out, and fails
+/// if any phase got slower than the threshold allows.
+fn report_bench(
+    root_dir: &str,
+    measurements: &[Measurement],
+    options: &BenchOptions,
+    save_baseline: bool,
+) -> Result<()> {
+    let comparisons = compare(&load_baseline(root_dir)?, measurements, options.threshold);
+    print!("{}", format_report(&comparisons));
+    save_results(root_dir, measurements, save_baseline)?;
+    let regressed = comparisons.iter().filter(|c| c.regressed).count();
+    if regressed > 0 && !save_baseline {
+        bail!("{regressed} phases regressed by more than {}%", options.threshold);
+    }
+    Ok(())
+}
+
+fn new(root_dir: &str, year: i16, day: u32, wait: bool) -> Result<()> {
+    let path = new_day(root_dir, day)?;
+    println!("Created {} and registered it in lib.rs", path.display());
+    if wait {
+        wait_until_unlocked(year, day);
+    }
+    for note in prefetch(root_dir, year, day) {
+        println!("  {note}");
+    }
+    Ok(())
+}
+
+fn wait_until_unlocked(year: i16, day: u32) {
+    if let Some(remaining) = time_until_unlock(year, day, SystemTime::now()) {
+        println!("Day {day} unlocks in {}, waiting", format_wait(remaining));
+        wait_for_unlock(year, day);
+    }
+}
+
+fn check(root_dir: &str, year: i16, days: &[Day], record: bool) -> Result<()> {
+    let rows = check_year(root_dir, year, days, record)?;
+    print!("{}", format_table(&rows));
+    let failed = rows.iter().filter(|r| matches!(r.status, Status::Fail | Status::Panicked)).count();
+    if failed > 0 {
+        bail!("{failed} answers did not match or panicked");
+    }
+    Ok(())
+}
+
+/// Submits `answer` for the current session's account, as long as it was
+/// solved from that account's own cached input and nothing else.
+#[cfg(feature = "download")]
+fn submit(
+    root_dir: &str,
+    year: i16,
+    day: u32,
+    part: u8,
+    answer: &str,
+    source: &InputSource,
+    input: &Input,
+) -> Result<()> {
+    use crate::{submit_answer, AocClient, InputCache, Ledger, Outcome};
+
+    let client = AocClient::from_env(&std::path::Path::new(root_dir).join("input"))?;
+    let account = client.account();
+    let own = InputCache::from_env(root_dir).get(&account, year, day).map(Input::new);
+    if *source != InputSource::Puzzle || own.as_deref() != Some(&**input) {
+        bail!("not submitting, the answer wasn't solved from this session's own day {day} input");
+    }
+    let mut ledger = Ledger::load(Ledger::path_for(root_dir, &account, day))?;
+    match submit_answer(&client, &mut ledger, year, day, part, answer)? {
+        Outcome::Submitted(verdict) => println!("Submitted {answer} for part {part}: {verdict}"),
+        Outcome::Refused(refusal) => println!("Not submitting {answer} for part {part}: {refusal}"),
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "download"))]
+fn submit(_: &str, _: i16, _: u32, _: u8, _: &str, _: &InputSource, _: &Input) -> Result<()> {
+    bail!("can't submit, utils was built without the download feature")
+}
+
+fn select_days<'a>(days: &'a [Day], selection: &str) -> Result<Vec<&'a Day>> {
+    if selection == "all" {
+        return Ok(days.iter().collect());
+    }
+    let Ok(n) = selection.parse::<u32>() else {
+        bail!("expected a day number or \"all\", got {selection:?}\n{USAGE}");
+    };
+    match days.iter().find(|d| d.day == n) {
+        Some(day) => Ok(vec![day]),
+        None => bail!("day {n} is not registered"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Solution;
+
+    struct Nothing;
+
+    impl Solution for Nothing {
+        type Parsed<'a> = &'a str;
+
+        fn parse(input: &str) -> Self::Parsed<'_> {
+            input
+        }
+        fn part1(_: &Self::Parsed<'_>) -> impl std::fmt::Display {
+            0
+        }
+        fn part2(_: &Self::Parsed<'_>) -> impl std::fmt::Display {
+            0
+        }
+    }
+
+    const DAYS: &[Day] = &[Day::new::<Nothing>(1), Day::new::<Nothing>(3)];
+
+    #[test]
+    fn test_select_days() {
+        let all = select_days(DAYS, "all").unwrap();
+        assert_eq!(vec![1, 3], all.iter().map(|d| d.day).collect::<Vec<_>>());
+        assert_eq!(3, select_days(DAYS, "3").unwrap()[0].day);
+        assert!(select_days(DAYS, "2").is_err());
+        assert!(select_days(DAYS, "two").is_err());
+    }
+}
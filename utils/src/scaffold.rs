@@ -0,0 +1,137 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+
+#[cfg(feature = "download")]
+use crate::{extract_examples, read_aoc_day, AocClient};
+
+/// Where a year crate keeps its day modules, `template.rs` and the `lib.rs`
+/// that lists them.
+pub fn days_dir(root_dir: &str) -> PathBuf {
+    Path::new(root_dir).join("src")
+}
+
+/// Fills in the template's `DayN` and `(N)` placeholders.
+pub fn render_template(template: &str, day: u32) -> String {
+    template
+        .replace("DayN", &format!("Day{day}"))
+        .replace("(N)", &format!("({day})"))
+}
+
+/// Adds `pub mod day<N>;` after the last day module and `day<N>::DAY` to the
+/// end of the `DAYS` slice. Returns `None` if `lib` doesn't look like it expects.
+pub fn register_day(lib: &str, day: u32) -> Option<String> {
+    let mut lines: Vec<String> = lib.lines().map(str::to_string).collect();
+    let last_mod = lines.iter().rposition(|l| l.starts_with("pub mod day"));
+    let days_start = lines.iter().position(|l| l.starts_with("pub const DAYS: &[Day] = &["))?;
+    let days_end = days_start + lines[days_start..].iter().position(|l| l == "];")?;
+    lines.insert(days_end, format!("    day{day}::DAY,"));
+    // With no days yet, the module goes just above the (empty) `DAYS`.
+    let mod_line = last_mod.map_or(days_start.saturating_sub(1), |i| i + 1);
+    lines.insert(mod_line, format!("pub mod day{day};"));
+    Some(lines.join("\n") + "\n")
+}
+
+/// Creates `day<N>.rs` from the template and registers it in `lib.rs`,
+/// refusing to touch a day that already exists. Returns the new file.
+pub fn new_day(root_dir: &str, day: u32) -> Result<PathBuf> {
+    if !(1..=25).contains(&day) {
+        bail!("there is no day {day}");
+    }
+    let dir = days_dir(root_dir);
+    let path = dir.join(format!("day{day}.rs"));
+    if path.exists() {
+        bail!("{} already exists", path.display());
+    }
+    let lib_path = dir.join("lib.rs");
+    let lib = std::fs::read_to_string(&lib_path).with_context(|| format!("could not read {}", lib_path.display()))?;
+    if lib.lines().any(|l| l == format!("pub mod day{day};")) {
+        bail!("day {day} is already registered in {}", lib_path.display());
+    }
+    let Some(lib) = register_day(&lib, day) else {
+        bail!("could not find the DAYS list in {}", lib_path.display());
+    };
+    let template_path = dir.join("template.rs");
+    let template = std::fs::read_to_string(&template_path)
+        .with_context(|| format!("could not read {}", template_path.display()))?;
+
+    std::fs::write(&path, render_template(&template, day))
+        .with_context(|| format!("could not write {}", path.display()))?;
+    std::fs::write(&lib_path, lib).with_context(|| format!("could not write {}", lib_path.display()))?;
+    Ok(path)
+}
+
+/// Downloads the input and extracts the examples ahead of the first run, if a
+/// session is configured. Returns what it managed to do, for the user.
+#[cfg(feature = "download")]
+pub fn prefetch(root_dir: &str, year: i16, day: u32) -> Vec<String> {
+    let input_dir = Path::new(root_dir).join("input");
+    if AocClient::from_env(&input_dir).is_err() {
+        return vec!["no session configured, skipping the download".to_string()];
+    }
+    let input = match read_aoc_day(root_dir, year, day) {
+        Ok(input) => format!("downloaded the input ({} lines)", input.lines().count()),
+        Err(e) => format!("could not download the input: {e}"),
+    };
+    let examples = match extract_examples(root_dir, year, day) {
+        Ok(examples) => format!("extracted {} examples", examples.blocks.len()),
+        Err(e) => format!("could not extract the examples: {e}"),
+    };
+    vec![input, examples]
+}
+
+#[cfg(not(feature = "download"))]
+pub fn prefetch(_root_dir: &str, _year: i16, _day: u32) -> Vec<String> {
+    vec!["built without the download feature, skipping the download".to_string()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::temp_dir;
+
+    const LIB: &str = "use utils::Day;
+
+pub mod day1;
+pub mod day2;
+
+pub const DAYS: &[Day] = &[
+    day1::DAY,
+    day2::DAY,
+];
+";
+
+    #[test]
+    fn test_register_day() {
+        let lib = register_day(LIB, 3).unwrap();
+        assert!(lib.contains("pub mod day2;\npub mod day3;\n\npub const DAYS"));
+        assert!(lib.contains("    day2::DAY,\n    day3::DAY,\n];"));
+
+        let empty = "use utils::Day;\n\npub const DAYS: &[Day] = &[\n];\n";
+        assert_eq!(
+            "use utils::Day;\npub mod day1;\n\npub const DAYS: &[Day] = &[\n    day1::DAY,\n];\n",
+            register_day(empty, 1).unwrap()
+        );
+        assert_eq!(None, register_day("fn main() {}", 1));
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = temp_dir("new_day");
+        let dir = days_dir(root.to_str().unwrap());
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("lib.rs"), LIB).unwrap();
+        std::fs::write(dir.join("template.rs"), "pub const DAY: Day = Day::new::<DayN>(N);\nstruct DayN;\n").unwrap();
+        let root = root.to_str().unwrap();
+
+        let path = new_day(root, 3).unwrap();
+        assert_eq!(
+            "pub const DAY: Day = Day::new::<Day3>(3);\nstruct Day3;\n",
+            std::fs::read_to_string(path).unwrap()
+        );
+        assert!(std::fs::read_to_string(dir.join("lib.rs")).unwrap().contains("day3::DAY"));
+        assert!(new_day(root, 3).is_err());
+        assert!(new_day(root, 2).is_err());
+        assert!(new_day(root, 26).is_err());
+    }
+}
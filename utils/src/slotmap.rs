@@ -29,6 +29,12 @@ pub struct SlotMap<T> {
     free_list: Option<Link>,
 }
 
+impl<T> Default for SlotMap<T> {
+    fn default() -> Self {
+        SlotMap::new()
+    }
+}
+
 impl<T> SlotMap<T> {
     pub fn new() -> Self {
         SlotMap {
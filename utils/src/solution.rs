@@ -0,0 +1,126 @@
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+use crate::bench::{measure_solution, visit_phases, BenchOptions, Measurement, PhaseVisitor};
+
+/// A single day's puzzle. `Parsed` may borrow from the input text, so parsing
+/// can be as lazy as the day wants (including just handing back the `&str`).
+pub trait Solution {
+    type Parsed<'a>;
+
+    fn parse(input: &str) -> Self::Parsed<'_>;
+    fn part1(data: &Self::Parsed<'_>) -> impl Display;
+    fn part2(data: &Self::Parsed<'_>) -> impl Display;
+}
+
+/// Type-erased registry entry for a `Solution`, so that a year's days can live
+/// in one slice and be looked up by number.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub day: u32,
+    solve: fn(&str) -> [String; 2],
+    solve_part: fn(&str, u8) -> String,
+    benchmark: fn(u32, &str, &BenchOptions) -> Vec<Measurement>,
+    phases: fn(&str, &mut PhaseVisitor),
+    time: fn(&str) -> [Duration; 3],
+}
+
+impl Day {
+    pub const fn new<S: Solution>(day: u32) -> Day {
+        Day {
+            day,
+            solve: solve::<S>,
+            solve_part: solve_part::<S>,
+            benchmark: measure_solution::<S>,
+            phases: visit_phases::<S>,
+            time: time::<S>,
+        }
+    }
+
+    pub fn solve(&self, input: &str) -> [String; 2] {
+        (self.solve)(input)
+    }
+
+    /// Like `solve`, but skips the other part, which may be slow or not
+    /// written yet.
+    pub fn solve_part(&self, input: &str, part: u8) -> String {
+        (self.solve_part)(input, part)
+    }
+
+    pub fn benchmark(&self, input: &str, options: &BenchOptions) -> Vec<Measurement> {
+        (self.benchmark)(self.day, input, options)
+    }
+
+    /// Calls `visit` with a closure for each `Phase`, for driving an outside
+    /// benchmark harness such as criterion.
+    pub fn phases(&self, input: &str, visit: &mut PhaseVisitor) {
+        (self.phases)(input, visit)
+    }
+
+    /// Wall-clock time of a single parse, part1 and part2 run, in that order.
+    pub fn time(&self, input: &str) -> [Duration; 3] {
+        (self.time)(input)
+    }
+}
+
+fn solve<S: Solution>(input: &str) -> [String; 2] {
+    let data = S::parse(input);
+    [S::part1(&data).to_string(), S::part2(&data).to_string()]
+}
+
+fn solve_part<S: Solution>(input: &str, part: u8) -> String {
+    let data = S::parse(input);
+    match part {
+        1 => S::part1(&data).to_string(),
+        _ => S::part2(&data).to_string(),
+    }
+}
+
+fn time<S: Solution>(input: &str) -> [Duration; 3] {
+    let start = Instant::now();
+    let data = S::parse(input);
+    let parsed = Instant::now();
+    S::part1(&data).to_string();
+    let part1 = Instant::now();
+    S::part2(&data).to_string();
+    [parsed - start, part1 - parsed, part1.elapsed()]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Parsed<'a> = Vec<i64>;
+
+        fn parse(input: &str) -> Self::Parsed<'_> {
+            input.split_whitespace().map(|x| x.parse().unwrap()).collect()
+        }
+        fn part1(data: &Self::Parsed<'_>) -> impl std::fmt::Display {
+            data.iter().sum::<i64>()
+        }
+        fn part2(data: &Self::Parsed<'_>) -> impl std::fmt::Display {
+            data.iter().product::<i64>()
+        }
+    }
+
+    #[test]
+    fn test_day_solve() {
+        let day = Day::new::<Sum>(3);
+        assert_eq!(3, day.day);
+        assert_eq!(["10".to_string(), "24".to_string()], day.solve("1 2 3 4\n"));
+        assert_eq!("24", day.solve_part("1 2 3 4\n", 2));
+    }
+
+    #[test]
+    fn test_day_phases() {
+        let mut visited = vec![];
+        Day::new::<Sum>(3).phases("1 2\n", &mut |phase, f| {
+            f();
+            visited.push(phase);
+        });
+        assert_eq!(Phase::ALL.to_vec(), visited);
+    }
+}
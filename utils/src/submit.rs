@@ -0,0 +1,389 @@
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::examples::html_text;
+use crate::InputError;
+#[cfg(feature = "download")]
+use crate::AocClient;
+
+/// What the server said about a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited(Option<Duration>),
+    /// The part is already solved, or part 2 was submitted before part 1.
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Reads the verdict out of the HTML page returned for a submission.
+    pub fn from_reply(html: &str) -> Option<Verdict> {
+        let text = article_text(html);
+        if text.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if text.contains("That's not the right answer") {
+            Some(if text.contains("too high") {
+                Verdict::TooHigh
+            } else if text.contains("too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            })
+        } else if text.contains("You gave an answer too recently") {
+            Some(Verdict::RateLimited(wait_time(&text)))
+        } else if text.contains("You don't seem to be solving the right level") {
+            Some(Verdict::WrongLevel)
+        } else {
+            None
+        }
+    }
+
+    fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::Wrong => "wrong",
+            Verdict::RateLimited(_) => "rate_limited",
+            Verdict::WrongLevel => "wrong_level",
+        }
+    }
+
+    fn from_str(s: &str) -> Option<Verdict> {
+        Some(match s {
+            "correct" => Verdict::Correct,
+            "too_high" => Verdict::TooHigh,
+            "too_low" => Verdict::TooLow,
+            "wrong" => Verdict::Wrong,
+            "rate_limited" => Verdict::RateLimited(None),
+            "wrong_level" => Verdict::WrongLevel,
+            _ => return None,
+        })
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "wrong (too high)"),
+            Verdict::TooLow => write!(f, "wrong (too low)"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::RateLimited(Some(wait)) => write!(f, "rate limited, wait {}s", wait.as_secs()),
+            Verdict::RateLimited(None) => write!(f, "rate limited"),
+            Verdict::WrongLevel => write!(f, "not the current level, already solved?"),
+        }
+    }
+}
+
+/// Why a submission was turned down locally, without asking the server.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadyCorrect(String),
+    KnownWrong,
+    NotBelow(i128),
+    NotAbove(i128),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadyCorrect(answer) => write!(f, "already solved with {answer}"),
+            Refusal::KnownWrong => write!(f, "already submitted and known to be wrong"),
+            Refusal::NotBelow(bound) => write!(f, "{bound} was too high, answer must be lower"),
+            Refusal::NotAbove(bound) => write!(f, "{bound} was too low, answer must be higher"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Submitted(Verdict),
+    Refused(Refusal),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Attempt {
+    pub timestamp: u64,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Every answer one account ever submitted for one day, one tab separated line
+/// per attempt.
+pub struct Ledger {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl Ledger {
+    /// `input/submissions/<account>/day<N>.tsv`: what the server said about one
+    /// account's answers says nothing about another's.
+    pub fn path_for(root_dir: &str, account: &str, day: u32) -> PathBuf {
+        Path::new(root_dir)
+            .join("input")
+            .join("submissions")
+            .join(account)
+            .join(format!("day{day}.tsv"))
+    }
+
+    pub fn load(path: impl Into<PathBuf>) -> Result<Ledger, InputError> {
+        let path = path.into();
+        let text = match std::fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(source) => return Err(InputError::Io { path, source }),
+        };
+        let attempts = text
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.splitn(4, '\t');
+                Some(Attempt {
+                    timestamp: fields.next()?.parse().ok()?,
+                    part: fields.next()?.parse().ok()?,
+                    verdict: Verdict::from_str(fields.next()?)?,
+                    answer: fields.next()?.to_string(),
+                })
+            })
+            .collect();
+        Ok(Ledger { path, attempts })
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    /// Whether submitting `answer` for `part` could possibly be correct, judging
+    /// only by what the server already told us.
+    pub fn check(&self, part: u8, answer: &str) -> Option<Refusal> {
+        let attempts = self.attempts.iter().filter(|a| a.part == part);
+        let mut lowest_too_high = None;
+        let mut highest_too_low = None;
+        for a in attempts {
+            if a.verdict == Verdict::Correct {
+                return Some(Refusal::AlreadyCorrect(a.answer.clone()));
+            }
+            if a.verdict.is_wrong() && a.answer == answer {
+                return Some(Refusal::KnownWrong);
+            }
+            let Ok(value) = a.answer.parse::<i128>() else {
+                continue;
+            };
+            match a.verdict {
+                Verdict::TooHigh => {
+                    lowest_too_high = Some(lowest_too_high.map_or(value, |b: i128| b.min(value)))
+                }
+                Verdict::TooLow => {
+                    highest_too_low = Some(highest_too_low.map_or(value, |b: i128| b.max(value)))
+                }
+                _ => (),
+            }
+        }
+        let value = answer.parse::<i128>().ok()?;
+        if let Some(bound) = lowest_too_high.filter(|&b| value >= b) {
+            return Some(Refusal::NotBelow(bound));
+        }
+        if let Some(bound) = highest_too_low.filter(|&b| value <= b) {
+            return Some(Refusal::NotAbove(bound));
+        }
+        None
+    }
+
+    pub fn record(&mut self, part: u8, answer: &str, verdict: Verdict) -> Result<(), InputError> {
+        use std::io::Write;
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        let io_error = |source| InputError::Io {
+            path: self.path.clone(),
+            source,
+        };
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(io_error)?;
+        writeln!(file, "{timestamp}\t{part}\t{}\t{answer}", verdict.as_str()).map_err(io_error)?;
+        self.attempts.push(Attempt {
+            timestamp,
+            part,
+            answer: answer.to_string(),
+            verdict,
+        });
+        Ok(())
+    }
+}
+
+/// Submits `answer` unless the ledger already rules it out, and records whatever
+/// the server replies.
+#[cfg(feature = "download")]
+pub fn submit_answer(
+    client: &AocClient,
+    ledger: &mut Ledger,
+    year: i16,
+    day: u32,
+    part: u8,
+    answer: &str,
+) -> Result<Outcome, InputError> {
+    let answer = answer.trim();
+    if let Some(refusal) = ledger.check(part, answer) {
+        return Ok(Outcome::Refused(refusal));
+    }
+    let verdict = client.submit(year, day, part, answer)?;
+    ledger.record(part, answer, verdict.clone())?;
+    Ok(Outcome::Submitted(verdict))
+}
+
+/// The text of the reply's `<article>` (the whole page if there is none).
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(inner, _)| inner);
+    html_text(article)
+}
+
+/// Parses e.g. "You have 1m 23s left to wait." into a duration.
+fn wait_time(text: &str) -> Option<Duration> {
+    let (before, _) = text.split_once("left to wait")?;
+    let (_, amount) = before.rsplit_once("have ")?;
+    let mut seconds = 0;
+    for token in amount.split_whitespace() {
+        let (n, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+        let n: u64 = n.parse().ok()?;
+        seconds += match unit {
+            "h" => n * 3600,
+            "m" => n * 60,
+            "s" => n,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::account_of;
+    use crate::test_server::temp_dir;
+
+    fn reply(text: &str) -> String {
+        format!("<html><body><main>\n<article><p>{text}</p></article>\n</main></body></html>")
+    }
+
+    #[test]
+    fn test_verdict_from_reply() {
+        let v = |text: &str| Verdict::from_reply(&reply(text));
+        assert_eq!(
+            Some(Verdict::Correct),
+            v("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.")
+        );
+        assert_eq!(
+            Some(Verdict::TooHigh),
+            v("That's not the right answer; your answer is too high.  Please wait one minute before trying again.")
+        );
+        assert_eq!(Some(Verdict::TooLow), v("That's not the right answer; your answer is too low."));
+        assert_eq!(Some(Verdict::Wrong), v("That's not the right answer.  If you're stuck, ..."));
+        assert_eq!(
+            Some(Verdict::RateLimited(Some(Duration::from_secs(83)))),
+            v("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait.")
+        );
+        assert_eq!(
+            Some(Verdict::RateLimited(Some(Duration::from_secs(7)))),
+            v("You gave an answer too recently.  You have 7s left to wait.")
+        );
+        assert_eq!(
+            Some(Verdict::WrongLevel),
+            v("You don't seem to be solving the right level.  Did you already complete it?")
+        );
+        assert_eq!(None, v("Something else entirely"));
+    }
+
+    #[test]
+    fn test_ledger_check() {
+        let dir = temp_dir("ledger_check");
+        let mut ledger = Ledger::load(dir.join("day1.tsv")).unwrap();
+        assert_eq!(None, ledger.check(1, "100"));
+        ledger.record(1, "100", Verdict::TooHigh).unwrap();
+        ledger.record(1, "50", Verdict::TooLow).unwrap();
+        ledger.record(1, "abc", Verdict::Wrong).unwrap();
+        ledger.record(1, "70", Verdict::RateLimited(None)).unwrap();
+
+        assert_eq!(Some(Refusal::KnownWrong), ledger.check(1, "abc"));
+        assert_eq!(Some(Refusal::KnownWrong), ledger.check(1, "100"));
+        assert_eq!(Some(Refusal::NotBelow(100)), ledger.check(1, "150"));
+        assert_eq!(Some(Refusal::NotAbove(50)), ledger.check(1, "-3"));
+        assert_eq!(None, ledger.check(1, "70"));
+        assert_eq!(None, ledger.check(1, "99"));
+        assert_eq!(None, ledger.check(2, "150"));
+
+        // Everything survives a reload from disk.
+        let reloaded = Ledger::load(dir.join("day1.tsv")).unwrap();
+        assert_eq!(ledger.attempts(), reloaded.attempts());
+
+        ledger.record(1, "70", Verdict::Correct).unwrap();
+        assert_eq!(Some(Refusal::AlreadyCorrect("70".into())), ledger.check(1, "71"));
+    }
+
+    #[test]
+    fn test_ledger_per_account() {
+        let root = temp_dir("ledger_per_account");
+        let root = root.to_str().unwrap();
+        let (alice, bob) = (account_of("alice"), account_of("bob"));
+        assert_ne!(Ledger::path_for(root, &alice, 4), Ledger::path_for(root, &bob, 4));
+
+        let mut ledger = Ledger::load(Ledger::path_for(root, &alice, 4)).unwrap();
+        ledger.record(1, "100", Verdict::TooHigh).unwrap();
+        ledger.record(2, "7", Verdict::Correct).unwrap();
+
+        let ledger = Ledger::load(Ledger::path_for(root, &bob, 4)).unwrap();
+        assert!(ledger.attempts().is_empty());
+        assert_eq!(None, ledger.check(1, "150"));
+        assert_eq!(None, ledger.check(2, "8"));
+        assert_eq!(2, Ledger::load(Ledger::path_for(root, &alice, 4)).unwrap().attempts().len());
+    }
+
+    #[cfg(feature = "download")]
+    #[test]
+    fn test_submit_answer() {
+        use crate::test_server::TestServer;
+
+        let server = TestServer::new(vec![
+            (200, &reply("That's not the right answer; your answer is too low.")),
+            (200, &reply("That's the right answer!")),
+        ]);
+        let client = AocClient::new("abc")
+            .unwrap()
+            .with_base_url(&server.url)
+            .with_min_interval(std::time::Duration::ZERO);
+        let dir = temp_dir("submit_answer");
+        let mut ledger = Ledger::load(dir.join("day3.tsv")).unwrap();
+
+        let submit = |ledger: &mut Ledger, answer| submit_answer(&client, ledger, 2023, 3, 2, answer);
+        assert_eq!(Outcome::Submitted(Verdict::TooLow), submit(&mut ledger, "10").unwrap());
+        assert_eq!(Outcome::Refused(Refusal::NotAbove(10)), submit(&mut ledger, "9").unwrap());
+        assert_eq!(Outcome::Submitted(Verdict::Correct), submit(&mut ledger, "11\n").unwrap());
+        assert_eq!(
+            Outcome::Refused(Refusal::AlreadyCorrect("11".into())),
+            submit(&mut ledger, "12").unwrap()
+        );
+
+        let requests = server.requests();
+        assert_eq!(2, requests.len());
+        assert!(requests[0].starts_with("POST /2023/day/3/answer "));
+        assert!(requests[0].ends_with("level=2&answer=10"));
+        assert!(requests[1].ends_with("level=2&answer=11"));
+    }
+}
@@ -0,0 +1,92 @@
+#[cfg(feature = "download")]
+pub use server::TestServer;
+
+#[cfg(feature = "download")]
+mod server {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc::{Receiver, channel};
+    use std::thread::JoinHandle;
+
+    /// A tiny HTTP server that answers each incoming request with the next canned
+    /// response, so the network code can be tested without touching the real site.
+    pub struct TestServer {
+        pub url: String,
+        requests: Receiver<String>,
+        handle: Option<JoinHandle<()>>,
+    }
+
+    impl TestServer {
+        pub fn new(responses: Vec<(u16, &str)>) -> TestServer {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let responses: Vec<_> = responses
+                .into_iter()
+                .map(|(status, body)| (status, body.to_string()))
+                .collect();
+            let (sender, requests) = channel();
+            let handle = std::thread::spawn(move || {
+                for (status, body) in responses {
+                    let (mut stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+                    let mut request = String::new();
+                    let mut content_length = 0;
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        if let Some((name, value)) = line.split_once(':')
+                            && name.eq_ignore_ascii_case("content-length")
+                        {
+                            content_length = value.trim().parse().unwrap();
+                        }
+                        request.push_str(&line);
+                        if line == "\r\n" || line.is_empty() {
+                            break;
+                        }
+                    }
+                    let mut request_body = vec![0; content_length];
+                    reader.read_exact(&mut request_body).unwrap();
+                    request.push_str(&String::from_utf8(request_body).unwrap());
+                    sender.send(request).unwrap();
+
+                    write!(
+                        stream,
+                        "HTTP/1.1 {status} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    )
+                    .unwrap();
+                }
+            });
+            TestServer {
+                url,
+                requests,
+                handle: Some(handle),
+            }
+        }
+
+        /// The raw text (request line, headers and body) of every request served so far.
+        pub fn requests(&self) -> Vec<String> {
+            self.requests.try_iter().collect()
+        }
+    }
+
+    impl Drop for TestServer {
+        fn drop(&mut self) {
+            // Only join if every canned response was used, otherwise the thread is
+            // still blocked in accept and the test already failed for other reasons.
+            if let Some(handle) = self.handle.take()
+                && handle.is_finished()
+            {
+                handle.join().unwrap();
+            }
+        }
+    }
+}
+
+/// A fresh, empty directory under the system temp dir.
+pub fn temp_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("advent_utils_{}_{name}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
@@ -0,0 +1,84 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::InputError;
+
+/// Puzzles unlock at midnight US Eastern (UTC-5, December is never in
+/// daylight saving time) on their day of December.
+const UNLOCK_HOUR_UTC: u64 = 5;
+
+/// When day `day` of `year` unlocks.
+pub fn unlock_time(year: i16, day: u32) -> SystemTime {
+    let days = days_from_civil(year as i64, 12, day as i64);
+    UNIX_EPOCH + Duration::from_secs(days as u64 * 24 * 60 * 60 + UNLOCK_HOUR_UTC * 60 * 60)
+}
+
+/// How long until the puzzle unlocks as of `now`, or `None` if it already has.
+pub fn time_until_unlock(year: i16, day: u32, now: SystemTime) -> Option<Duration> {
+    unlock_time(year, day).duration_since(now).ok().filter(|d| !d.is_zero())
+}
+
+/// Fails with `NotUnlocked` if the puzzle isn't out yet, rather than sending a
+/// request the server will only answer with a complaint.
+pub fn ensure_unlocked(year: i16, day: u32) -> Result<(), InputError> {
+    match time_until_unlock(year, day, SystemTime::now()) {
+        Some(remaining) => Err(InputError::NotUnlocked { year, day, remaining }),
+        None => Ok(()),
+    }
+}
+
+/// Sleeps until the puzzle unlocks, plus a second so our clock being slightly
+/// ahead of the server's doesn't get us an early request.
+pub fn wait_for_unlock(year: i16, day: u32) {
+    if let Some(remaining) = time_until_unlock(year, day, SystemTime::now()) {
+        std::thread::sleep(remaining + Duration::from_secs(1));
+    }
+}
+
+/// `3h 12m 5s`, for telling a human how long to wait.
+pub fn format_wait(d: Duration) -> String {
+    let secs = d.as_secs();
+    match (secs / 3600, secs / 60 % 60, secs % 60) {
+        (0, 0, s) => format!("{s}s"),
+        (0, m, s) => format!("{m}m {s}s"),
+        (h, m, s) => format!("{h}h {m}m {s}s"),
+    }
+}
+
+/// Days since 1970-01-01 of a proleptic Gregorian date (Howard Hinnant's
+/// `days_from_civil`).
+fn days_from_civil(y: i64, m: i64, d: i64) -> i64 {
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * ((m + 9) % 12) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(secs: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        // 2023-12-01T05:00:00Z and 2024-12-25T05:00:00Z.
+        assert_eq!(utc(1701406800), unlock_time(2023, 1));
+        assert_eq!(utc(1735102800), unlock_time(2024, 25));
+        assert_eq!(Some(Duration::from_secs(1)), time_until_unlock(2023, 1, utc(1701406799)));
+        assert_eq!(None, time_until_unlock(2023, 1, utc(1701406800)));
+        assert_eq!(None, time_until_unlock(2023, 1, utc(1701500000)));
+        assert!(ensure_unlocked(2023, 1).is_ok());
+        assert!(matches!(ensure_unlocked(2999, 1), Err(InputError::NotUnlocked { .. })));
+    }
+
+    #[test]
+    fn test_format_wait() {
+        assert_eq!("5s", format_wait(Duration::from_secs(5)));
+        assert_eq!("2m 0s", format_wait(Duration::from_secs(120)));
+        assert_eq!("26h 1m 1s", format_wait(Duration::from_secs(93661)));
+    }
+}
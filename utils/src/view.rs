@@ -0,0 +1,222 @@
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+
+use crate::{Coord, Grid};
+
+/// A 2D grid seen through a transform, without copying it: transposed,
+/// rotated, flipped or cropped, any number of times. Views index like a
+/// `Grid<T, 2>`, with `[0, 0]` at the top left of whatever the view shows,
+/// so an algorithm written for one direction works for all of them.
+///
+/// `G` is `&Grid` for a read-only view (`GridView`) and `&mut Grid` for one
+/// that writes through to the grid (`GridViewMut`).
+#[derive(Clone, Copy, Debug)]
+pub struct View<G> {
+    grid: G,
+    /// The grid coordinate shown at `[0, 0]`.
+    origin: Coord<2>,
+    /// How far one step along each view axis moves on the grid.
+    steps: [Coord<2>; 2],
+    dims: [i64; 2],
+}
+
+pub type GridView<'a, T> = View<&'a Grid<T, 2>>;
+pub type GridViewMut<'a, T> = View<&'a mut Grid<T, 2>>;
+
+impl<T> Grid<T, 2> {
+    pub fn view(&self) -> GridView<'_, T> {
+        View::new(self)
+    }
+
+    pub fn view_mut(&mut self) -> GridViewMut<'_, T> {
+        View::new(self)
+    }
+}
+
+impl<T, G: Deref<Target = Grid<T, 2>>> View<G> {
+    fn new(grid: G) -> Self {
+        let dims = grid.get_dims();
+        View {
+            grid,
+            origin: Coord::from([0, 0]),
+            steps: [[1, 0].into(), [0, 1].into()],
+            dims,
+        }
+    }
+
+    pub fn get_dims(&self) -> [i64; 2] {
+        self.dims
+    }
+
+    pub fn contains<U: Into<Coord<2>>>(&self, coord: U) -> bool {
+        let coord = coord.into();
+        (0..2).all(|i| (0..self.dims[i]).contains(&coord[i]))
+    }
+
+    /// Where `coord` in the view is on the underlying grid.
+    pub fn to_grid_coord<U: Into<Coord<2>>>(&self, coord: U) -> Option<Coord<2>> {
+        let coord = coord.into();
+        self.contains(coord)
+            .then(|| self.origin + self.steps[0] * coord[0] + self.steps[1] * coord[1])
+    }
+
+    pub fn get<U: Into<Coord<2>>>(&self, coord: U) -> Option<&T> {
+        self.to_grid_coord(coord).map(|c| &self.grid[c])
+    }
+
+    /// Every coordinate in the view, left to right along each row, top row
+    /// first.
+    pub fn iter_coords(&self) -> impl Iterator<Item = Coord<2>> + use<T, G> {
+        let [width, height] = self.dims;
+        (0..height).flat_map(move |row| (0..width).map(move |col| Coord::from([col, row])))
+    }
+
+    /// Swaps rows and columns, so the view's rows are the grid's columns.
+    pub fn transpose(self) -> Self {
+        View {
+            steps: [self.steps[1], self.steps[0]],
+            dims: [self.dims[1], self.dims[0]],
+            ..self
+        }
+    }
+
+    /// Mirrors left and right.
+    pub fn flip_horizontal(self) -> Self {
+        View {
+            origin: self.origin + self.steps[0] * (self.dims[0] - 1),
+            steps: [self.steps[0] * -1, self.steps[1]],
+            ..self
+        }
+    }
+
+    /// Mirrors top and bottom.
+    pub fn flip_vertical(self) -> Self {
+        View {
+            origin: self.origin + self.steps[1] * (self.dims[1] - 1),
+            steps: [self.steps[0], self.steps[1] * -1],
+            ..self
+        }
+    }
+
+    /// A quarter turn clockwise: the left column becomes the top row.
+    pub fn rotate_cw(self) -> Self {
+        self.flip_vertical().transpose()
+    }
+
+    pub fn rotate_180(self) -> Self {
+        self.flip_horizontal().flip_vertical()
+    }
+
+    /// A quarter turn anticlockwise: the top row becomes the left column.
+    pub fn rotate_ccw(self) -> Self {
+        self.transpose().flip_vertical()
+    }
+
+    /// The `dims` rectangle with its top left corner at `min`, which may be
+    /// empty. Panics if the rectangle doesn't fit in the view.
+    pub fn crop(self, min: impl Into<Coord<2>>, dims: [i64; 2]) -> Self {
+        let min = min.into();
+        assert!(
+            (0..2).all(|i| min[i] >= 0 && dims[i] >= 0 && min[i] + dims[i] <= self.dims[i]),
+            "{dims:?} at {min:?} doesn't fit in {:?}",
+            self.dims
+        );
+        View {
+            origin: self.origin + self.steps[0] * min[0] + self.steps[1] * min[1],
+            dims,
+            ..self
+        }
+    }
+
+    /// Copies what the view shows into a grid of its own.
+    pub fn to_grid(&self) -> Grid<T, 2>
+    where
+        T: Clone,
+    {
+        Grid::from_data(self.iter_coords().map(|c| self[c].clone()).collect(), &self.dims)
+    }
+}
+
+impl<T, G: DerefMut<Target = Grid<T, 2>>> View<G> {
+    pub fn get_mut<U: Into<Coord<2>>>(&mut self, coord: U) -> Option<&mut T> {
+        self.to_grid_coord(coord).map(|c| &mut self.grid[c])
+    }
+}
+
+impl<T, G: Deref<Target = Grid<T, 2>>, Idx: Into<Coord<2>>> Index<Idx> for View<G> {
+    type Output = T;
+
+    fn index(&self, index: Idx) -> &Self::Output {
+        let index = index.into();
+        self.get(index)
+            .unwrap_or_else(|| panic!("Invalid index {:?}", index))
+    }
+}
+
+impl<T, G: DerefMut<Target = Grid<T, 2>>, Idx: Into<Coord<2>>> IndexMut<Idx> for View<G> {
+    fn index_mut(&mut self, index: Idx) -> &mut Self::Output {
+        let index = index.into();
+        self.get_mut(index)
+            .unwrap_or_else(|| panic!("Invalid index {:?}", index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(text: &str) -> Grid<char, 2> {
+        Grid::parse(text, |b, _| Ok::<_, ()>(b as char)).unwrap()
+    }
+
+    #[test]
+    fn test_transforms() {
+        let abc = grid("abc\ndef\n");
+        let shown = |view: GridView<char>| view.to_grid().render().to_string();
+        assert_eq!("abc\ndef\n", shown(abc.view()));
+        assert_eq!("ad\nbe\ncf\n", shown(abc.view().transpose()));
+        assert_eq!("cba\nfed\n", shown(abc.view().flip_horizontal()));
+        assert_eq!("def\nabc\n", shown(abc.view().flip_vertical()));
+        assert_eq!("da\neb\nfc\n", shown(abc.view().rotate_cw()));
+        assert_eq!("fed\ncba\n", shown(abc.view().rotate_180()));
+        assert_eq!("cf\nbe\nad\n", shown(abc.view().rotate_ccw()));
+        assert_eq!(abc, abc.view().rotate_cw().rotate_cw().rotate_180().to_grid());
+        assert_eq!(abc, abc.view().rotate_ccw().rotate_cw().to_grid());
+        assert_eq!("e\nb\n", shown(abc.view().rotate_180().crop([1, 0], [1, 2])));
+        assert_eq!("ef\n", shown(abc.view().crop([1, 1], [2, 1])));
+        assert_eq!("eb\n", shown(abc.view().crop([1, 0], [2, 2]).rotate_cw().crop([0, 0], [2, 1])));
+    }
+
+    #[test]
+    fn test_crop_edges() {
+        let abc = grid("abc\ndef\n");
+        assert_eq!([0, 2], abc.view().crop([3, 0], [0, 2]).get_dims());
+        let empty = abc.view().crop([1, 1], [2, 0]).rotate_cw();
+        assert_eq!([0, 2], empty.get_dims());
+        assert_eq!(None, empty.get([0, 0]));
+        assert_eq!(0, empty.iter_coords().count());
+        assert_eq!("def\n", abc.view().crop([0, 1], [3, 1]).to_grid().render().to_string());
+
+        let fits = |min: [i64; 2], dims| std::panic::catch_unwind(|| abc.view().crop(min, dims).get_dims()).is_ok();
+        assert!(!fits([2, 0], [2, 1]));
+        assert!(!fits([0, 0], [3, 3]));
+        assert!(!fits([-1, 0], [1, 1]));
+        assert!(!fits([4, 0], [0, 0]));
+    }
+
+    #[test]
+    fn test_indexing() {
+        let mut abc = grid("abc\ndef\n");
+        let view = abc.view().rotate_cw();
+        assert_eq!([2, 3], view.get_dims());
+        assert_eq!('c', view[[1, 2]]);
+        assert_eq!(Some(Coord::from([2, 0])), view.to_grid_coord([1, 2]));
+        assert_eq!(None, view.get([2, 0]));
+        assert!(!view.contains([-1, 0]));
+
+        let mut view = abc.view_mut().transpose();
+        view[[1, 0]] = 'x';
+        *view.get_mut([0, 2]).unwrap() = 'y';
+        assert_eq!(None, view.get_mut([0, 3]));
+        assert_eq!("aby\nxef\n", abc.render().to_string());
+    }
+}